@@ -0,0 +1,20 @@
+use std::error::Error;
+use std::fmt;
+
+/// Defines types of errors that might occur during fallible fraction arithmetic.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ArithmeticError {
+    Overflow,
+    DivisionByZero,
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArithmeticError::Overflow => write!(f, "Fraction arithmetic overflowed"),
+            ArithmeticError::DivisionByZero => write!(f, "Cannot divide by zero"),
+        }
+    }
+}
+
+impl Error for ArithmeticError {}
@@ -0,0 +1,22 @@
+use std::error::Error;
+use std::fmt;
+
+/// Defines types of errors that might occur when constructing fractions from raw parts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConstructionError {
+    ZeroDenominator,
+    Overflow,
+}
+
+impl fmt::Display for ConstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConstructionError::ZeroDenominator => {
+                write!(f, "Fraction cannot have a zero denominator")
+            }
+            ConstructionError::Overflow => write!(f, "Fraction does not fit in the backing type"),
+        }
+    }
+}
+
+impl Error for ConstructionError {}
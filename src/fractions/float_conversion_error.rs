@@ -0,0 +1,22 @@
+use std::error::Error;
+use std::fmt;
+
+/// Defines types of errors that might occur when converting floats to fractions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FloatConversionError {
+    NotFinite,
+    OutOfRange,
+}
+
+impl fmt::Display for FloatConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FloatConversionError::NotFinite => write!(f, "Cannot convert a non-finite float"),
+            FloatConversionError::OutOfRange => {
+                write!(f, "Float is out of range for the backing type")
+            }
+        }
+    }
+}
+
+impl Error for FloatConversionError {}
@@ -0,0 +1,88 @@
+use num::Integer;
+
+use super::util::reduce;
+use super::Fraction;
+
+/// Structure-of-arrays container for many fractions,
+/// keeping all numerators and all denominators in separate contiguous buffers
+/// for cache-friendly bulk processing.
+///
+/// Unlike `Fraction`, the stored pairs are not required to be reduced,
+/// but every denominator is non-zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FractionSoa<T = i32> {
+    numerators: Vec<T>,
+    denominators: Vec<T>,
+}
+
+impl<T: Integer + Copy> FractionSoa<T> {
+    /// Create a container from parallel arrays of numerators and denominators.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays have different lengths or any denominator is zero.
+    pub fn new(numerators: Vec<T>, denominators: Vec<T>) -> FractionSoa<T> {
+        if numerators.len() != denominators.len() {
+            panic!("Numerator and denominator arrays must have the same length");
+        }
+
+        if denominators.iter().any(|d| d.is_zero()) {
+            panic!("Fraction cannot have a zero denominator");
+        }
+
+        FractionSoa {
+            numerators,
+            denominators,
+        }
+    }
+
+    pub fn numerators(&self) -> &[T] {
+        &self.numerators
+    }
+
+    pub fn denominators(&self) -> &[T] {
+        &self.denominators
+    }
+
+    pub fn len(&self) -> usize {
+        self.numerators.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.numerators.is_empty()
+    }
+
+    /// Reduces every pair in place, exactly as `Fraction::new` would.
+    pub fn reduce_all(&mut self) {
+        for (n, d) in self.numerators.iter_mut().zip(self.denominators.iter_mut()) {
+            let (reduced_n, reduced_d) = reduce(*n, *d);
+
+            *n = reduced_n;
+            *d = reduced_d;
+        }
+    }
+}
+
+impl<T: Integer + Copy> From<Vec<Fraction<T>>> for FractionSoa<T> {
+    fn from(fracs: Vec<Fraction<T>>) -> Self {
+        let (numerators, denominators) = fracs
+            .into_iter()
+            .map(|f| (f.numerator, f.denominator))
+            .unzip();
+
+        FractionSoa {
+            numerators,
+            denominators,
+        }
+    }
+}
+
+impl<T: Integer + Copy> From<FractionSoa<T>> for Vec<Fraction<T>> {
+    fn from(soa: FractionSoa<T>) -> Self {
+        soa.numerators
+            .into_iter()
+            .zip(soa.denominators)
+            .map(|(n, d)| Fraction::new(n, d))
+            .collect()
+    }
+}
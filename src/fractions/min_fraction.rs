@@ -0,0 +1,22 @@
+use std::cmp::Ordering;
+
+use num::Integer;
+
+use super::Fraction;
+
+/// Wrapper around a fraction with reversed ordering,
+/// so that e.g. `BinaryHeap<MinFraction>` behaves as a min-heap.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MinFraction<T = i32>(pub Fraction<T>);
+
+impl<T: Integer + Copy> PartialOrd for MinFraction<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer + Copy> Ord for MinFraction<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
@@ -1,19 +1,41 @@
-mod auxiliary;
-
+pub mod arithmetic_error;
+pub mod construction_error;
+pub mod float_conversion_error;
+pub mod fraction_soa;
 pub mod macros;
+pub mod min_fraction;
 pub mod parse_error;
+pub mod parser;
+pub mod rational_value;
+pub mod scaler;
+pub mod sign;
+pub mod unsigned_fraction;
+pub mod util;
+pub mod zero_denominator_error;
 
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt;
+use std::iter::{self, Product, Sum};
+use std::mem;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use std::str::FromStr;
 
 use itertools::Itertools;
-use num::integer::lcm;
-use num::{abs, Integer, Signed};
+use num::integer::{gcd, lcm};
+use num::rational::Ratio;
+use num::traits::checked_pow;
+use num::traits::{CheckedDiv, CheckedRem};
+use num::traits::{FromBytes, ToBytes};
+use num::{abs, CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, Integer, Signed, ToPrimitive};
 
-use auxiliary::{normalize_sign, reduce};
-use parse_error::FractionParseError;
+use arithmetic_error::ArithmeticError;
+use construction_error::ConstructionError;
+use float_conversion_error::FloatConversionError;
+use parse_error::{Component, FractionParseError};
+use sign::Sign;
+use util::{int_to_string_radix, normalize_sign, reduce};
+use zero_denominator_error::ZeroDenominatorError;
 
 /// Structure representing a common fraction,
 /// ie. one where the numerator is an integer
@@ -24,13 +46,13 @@ use parse_error::FractionParseError;
 /// e.g. 14/24 will become 7/12.
 ///
 /// If the fraction is negative, its sign is kept in the numerator.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub struct Fraction<T = i32> {
     numerator: T,
     denominator: T,
 }
 
-impl<T: Integer + Signed + Copy> Fraction<T> {
+impl<T: Integer + Copy> Fraction<T> {
     /// Create a new fraction from numerator and denominator.
     ///
     /// # Panics
@@ -41,8 +63,1920 @@ impl<T: Integer + Signed + Copy> Fraction<T> {
             panic!("Fraction cannot have a zero denominator");
         }
 
-        let (n, d) = normalize_sign(numerator, denominator);
-        let (numerator, denominator) = reduce(n, d);
+        Fraction::from_valid_parts(numerator, denominator)
+    }
+
+    /// Create fractions from an iterator of `(numerator, denominator)` pairs.
+    ///
+    /// Every pair is normalized and reduced the same way `new` does it,
+    /// but instead of panicking, the first pair with a zero denominator
+    /// is reported as an error carrying its index.
+    pub fn from_iter_parts<I: IntoIterator<Item = (T, T)>>(
+        pairs: I,
+    ) -> Result<Vec<Fraction<T>>, ZeroDenominatorError> {
+        pairs
+            .into_iter()
+            .enumerate()
+            .map(|(index, (numerator, denominator))| {
+                if denominator.is_zero() {
+                    return Err(ZeroDenominatorError::at(index));
+                }
+
+                Ok(Fraction::from_valid_parts(numerator, denominator))
+            })
+            .collect()
+    }
+
+    pub fn numerator(&self) -> T {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> T {
+        self.denominator
+    }
+
+    /// Replaces both parts of the fraction, normalizing and reducing them
+    /// the same way `new` does.
+    ///
+    /// If the denominator is zero, an error is returned and the fraction is left unchanged.
+    pub fn set(&mut self, numerator: T, denominator: T) -> Result<(), ZeroDenominatorError> {
+        if denominator.is_zero() {
+            return Err(ZeroDenominatorError::new());
+        }
+
+        *self = Fraction::from_valid_parts(numerator, denominator);
+
+        Ok(())
+    }
+
+    /// Applies `f` to both the numerator and the denominator,
+    /// e.g. to change the backing type in ways not covered by `From`.
+    ///
+    /// The caller is responsible for `f` preserving the invariants of a fraction:
+    /// the mapped parts must stay coprime and the denominator must stay positive.
+    pub fn map<U, F: Fn(T) -> U>(&self, f: F) -> Fraction<U> {
+        Fraction::<U> {
+            numerator: f(self.numerator),
+            denominator: f(self.denominator),
+        }
+    }
+
+    /// Returns a tuple in the form `(numerator, denominator)`.
+    pub fn get_as_tuple(&self) -> (T, T) {
+        (self.numerator, self.denominator)
+    }
+
+    /// Rounds the fraction to the nearest integer, rounding ties to the even one
+    /// (banker's rounding), e.g. `1/2` becomes `0`, `3/2` becomes `2` and `5/2` becomes `2`.
+    pub fn round_half_even(&self) -> T {
+        let (floor, rest) = self.numerator.div_mod_floor(&self.denominator);
+        let to_ceil = self.denominator - rest;
+
+        match rest.cmp(&to_ceil) {
+            Ordering::Less => floor,
+            Ordering::Greater => floor + T::one(),
+            Ordering::Equal if floor.is_even() => floor,
+            Ordering::Equal => floor + T::one(),
+        }
+    }
+
+    /// Returns the greatest common divisor of the numerator and `n`.
+    pub fn gcd_with_int(&self, n: T) -> T {
+        gcd(self.numerator, n)
+    }
+
+    /// Divides the fraction by `n` if the numerator is evenly divisible by it,
+    /// e.g. `6/5` divided by `3` gives `Some(2/5)`, but by `4` gives `None`.
+    ///
+    /// Also returns `None` if `n` is zero or the quotient overflows, as `T::MIN / -1` does.
+    pub fn divide_exact(&self, n: T) -> Option<Fraction<T>>
+    where
+        T: CheckedDiv + CheckedRem,
+    {
+        if !self.numerator.checked_rem(&n)?.is_zero() {
+            return None;
+        }
+
+        let numerator = self.numerator.checked_div(&n)?;
+
+        Some(Fraction::<T> {
+            numerator,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Returns `k` if the denominator is `2^k`, i.e. if the fraction is dyadic,
+    /// e.g. `3/8` gives `Some(3)` and `1/3` gives `None`.
+    pub fn dyadic_exponent(&self) -> Option<u32> {
+        let two = T::one() + T::one();
+        let mut denominator = self.denominator;
+        let mut exponent = 0;
+
+        while denominator.is_even() {
+            denominator = denominator / two;
+            exponent += 1;
+        }
+
+        if denominator.is_one() {
+            Some(exponent)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the largest unit fraction not exceeding this one, i.e. `1/ceil(1/self)`,
+    /// which is the greedy step of an Egyptian fraction decomposition,
+    /// e.g. `2/3` gives `1/2`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fraction is not positive.
+    pub fn largest_unit_fraction_below(&self) -> Fraction<T> {
+        if self.numerator <= T::zero() {
+            panic!("Fraction must be positive");
+        }
+
+        let (quotient, rest) = self.denominator.div_rem(&self.numerator);
+        let denominator = if rest.is_zero() {
+            quotient
+        } else {
+            quotient + T::one()
+        };
+
+        Fraction::<T> {
+            numerator: T::one(),
+            denominator,
+        }
+    }
+
+    /// Returns `Some('-')` if the fraction is negative, and `None` otherwise.
+    pub fn sign_char(&self) -> Option<char> {
+        if self.numerator < T::zero() {
+            Some('-')
+        } else {
+            None
+        }
+    }
+
+    /// Returns the fraction as an integer if it is a whole number,
+    /// e.g. `6/3` gives `Some(2)`, but `1/2` gives `None`.
+    pub fn try_into_integer(&self) -> Option<T> {
+        if self.denominator.is_one() {
+            Some(self.numerator)
+        } else {
+            None
+        }
+    }
+
+    /// Splits the fraction into a whole part and a proper fractional part,
+    /// both carrying the sign of the original fraction,
+    /// e.g. `7/3` becomes `(2, 1/3)` and `-7/3` becomes `(-2, -1/3)`.
+    ///
+    /// Since the denominator is always positive,
+    /// this never overflows, even for numerators equal to `T::MIN`.
+    pub fn to_mixed(&self) -> (T, Fraction<T>) {
+        let (whole, rest) = self.numerator.div_rem(&self.denominator);
+        let (numerator, denominator) = reduce(rest, self.denominator);
+
+        (
+            whole,
+            Fraction::<T> {
+                numerator,
+                denominator,
+            },
+        )
+    }
+
+    /// Returns the cross products `(a*d, c*b)` of `a/b` and `c/d`,
+    /// whose order is the order of the two fractions, since both denominators are positive.
+    ///
+    /// Useful for showing how a comparison is decided by hand;
+    /// `Ord` itself compares without these products, since they can overflow
+    /// (panicking in debug builds) even when both fractions fit.
+    pub fn cross_multiply(&self, other: &Self) -> (T, T) {
+        (
+            self.numerator * other.denominator,
+            other.numerator * self.denominator,
+        )
+    }
+
+    /// Multiplies both numerator and denominator by a positive `factor`.
+    ///
+    /// The result is equal to `self`, since fractions are always stored reduced;
+    /// use `scale_pair` to see the unreduced form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is not positive.
+    pub fn scale_num_den(&self, factor: T) -> Fraction<T> {
+        let (numerator, denominator) = self.scale_pair(factor);
+
+        Fraction::from_valid_parts(numerator, denominator)
+    }
+
+    /// Returns the unreduced `(numerator, denominator)` pair
+    /// with both parts multiplied by a positive `factor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is not positive.
+    pub fn scale_pair(&self, factor: T) -> (T, T) {
+        if factor <= T::zero() {
+            panic!("Scaling factor must be positive");
+        }
+
+        (self.numerator * factor, self.denominator * factor)
+    }
+
+    /// Compares two fractions by their exact values.
+    ///
+    /// Unlike `f64::total_cmp` this needs no special cases,
+    /// since every fraction is a distinct, comparable value;
+    /// it is the same total order as `Ord`, which never overflows.
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+
+    /// Returns the mediant `(a + c)/(b + d)` of `a/b` and `c/d`,
+    /// which always lies between them.
+    ///
+    /// For neighbors in the Stern-Brocot tree this is their child node.
+    pub fn mediant_between(left: &Self, right: &Self) -> Fraction<T> {
+        Fraction::from_valid_parts(
+            left.numerator + right.numerator,
+            left.denominator + right.denominator,
+        )
+    }
+
+    /// Returns `true` if the fraction lies strictly between `left` and `right`,
+    /// i.e. in the Stern-Brocot subtree rooted at their mediant.
+    pub fn contains_in_subtree(&self, left: &Self, right: &Self) -> bool {
+        left < self && self < right
+    }
+
+    /// Raises the fraction to a non-negative integer power
+    /// by repeated squaring.
+    ///
+    /// Powers of a reduced fraction are already reduced,
+    /// so this overflows exactly when the result (or a partial power) does not fit.
+    pub fn pow_reduced(&self, exp: u32) -> Fraction<T> {
+        let mut result = Fraction::from_valid_parts(T::one(), T::one());
+        let mut base = *self;
+        let mut exp = exp;
+
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result *= base;
+            }
+
+            exp /= 2;
+            if exp > 0 {
+                base *= base;
+            }
+        }
+
+        result
+    }
+
+    /// Returns `true` if the fraction is equal to `num/den` after reducing it,
+    /// e.g. `1/2` is equal to `(2, 4)`.
+    ///
+    /// A zero denominator never compares equal.
+    pub fn eq_tuple(&self, num: T, den: T) -> bool {
+        !den.is_zero() && *self == Fraction::from_valid_parts(num, den)
+    }
+
+    /// Returns `true` if the fraction lies in the inclusive range `[low, high]`.
+    pub fn is_in_range(&self, low: &Self, high: &Self) -> bool {
+        low <= self && self <= high
+    }
+}
+
+impl<T: Integer + Signed + Copy> Fraction<T> {
+    /// Returns `true` if the fraction is proper,
+    /// i.e. the absolute value of the numerator
+    /// is lower than the denominator.
+    pub fn is_proper(&self) -> bool {
+        abs(self.numerator) < self.denominator
+    }
+
+    /// Returns the fractional part of the fraction, discarding its integer part,
+    /// so that the result lies in `(-1, 1)` and keeps the original sign,
+    /// e.g. `7/3` becomes `1/3` and `-7/3` becomes `-1/3`.
+    pub fn wrap_to_unit(&self) -> Fraction<T> {
+        let (numerator, denominator) = reduce(self.numerator % self.denominator, self.denominator);
+
+        Fraction::<T> {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Returns the sign of the fraction as a bare integer:
+    /// `-1` if negative, `0` if zero and `1` if positive.
+    pub fn sign(&self) -> T {
+        self.numerator.signum()
+    }
+
+    /// Splits the fraction into its sign and its absolute value,
+    /// e.g. `-3/4` becomes `(Sign::Negative, 3/4)`.
+    ///
+    /// A zero is split into `(Sign::Positive, 0/1)`.
+    ///
+    /// # Panics
+    ///
+    /// Overflows (panicking in debug builds) if the numerator is `T::MIN`.
+    pub fn split_sign(&self) -> (Sign, Fraction<T>) {
+        let sign = if self.numerator.is_negative() {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+
+        let magnitude = Fraction::<T> {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator,
+        };
+
+        (sign, magnitude)
+    }
+
+    /// Returns a new fraction that is the inverse of this fraction, i.e. 1/f.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the original fraction is a zero.
+    ///
+    /// A negative fraction moves its sign from the new denominator to the new numerator,
+    /// which overflows (panicking in debug builds) if its numerator is `T::MIN`.
+    /// Use `checked_reciprocal` to handle this case instead.
+    pub fn reciprocal(&self) -> Fraction<T> {
+        if self.numerator.is_zero() {
+            panic!("Cannot reverse a zero");
+        }
+
+        let (numerator, denominator) = normalize_sign(self.denominator, self.numerator);
+
+        Fraction::<T> {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Returns this fraction of an integer `amount`, i.e. `self * amount`,
+    /// so that one third of 100 is `frac!(1, 3).of(100)`.
+    pub fn of(&self, amount: T) -> Fraction<T> {
+        *self * amount
+    }
+
+    /// Returns the reciprocal of the fraction, or `default` if the fraction is a zero.
+    pub fn reciprocal_or(&self, default: Fraction<T>) -> Fraction<T> {
+        if self.numerator.is_zero() {
+            default
+        } else {
+            self.reciprocal()
+        }
+    }
+
+    /// Computes the exact weighted mean `sum(v_i * w_i) / sum(w_i)`.
+    ///
+    /// Returns `None` if the slices have different lengths
+    /// or if the weights sum to zero.
+    pub fn weighted_mean(values: &[Fraction<T>], weights: &[Fraction<T>]) -> Option<Fraction<T>> {
+        if values.len() != weights.len() {
+            return None;
+        }
+
+        let zero = Fraction::from_valid_parts(T::zero(), T::one());
+        let (weighted_sum, weight_sum) = values
+            .iter()
+            .zip(weights)
+            .fold((zero, zero), |(weighted_sum, weight_sum), (&v, &w)| {
+                (weighted_sum + v * w, weight_sum + w)
+            });
+
+        if weight_sum.numerator.is_zero() {
+            return None;
+        }
+
+        Some(weighted_sum / weight_sum)
+    }
+
+    /// Returns the exact midpoint of this fraction and `other`.
+    ///
+    /// The whole and fractional parts of both operands are halved separately,
+    /// so the computation only overflows if the midpoint itself does not fit
+    /// or the least common multiple of the denominators does not,
+    /// rather than whenever the naive `(a + b) / 2` would.
+    pub fn midpoint(&self, other: &Self) -> Fraction<T> {
+        let two = T::one() + T::one();
+
+        let (whole_a, rest_a) = self.numerator.div_mod_floor(&self.denominator);
+        let (whole_b, rest_b) = other.numerator.div_mod_floor(&other.denominator);
+        let (half_a, odd_a) = whole_a.div_mod_floor(&two);
+        let (half_b, odd_b) = whole_b.div_mod_floor(&two);
+
+        // the fractional parts scaled to the common denominator are both less than it,
+        // so their sum is split into a carry and a remainder without overflowing
+        let denom = lcm(self.denominator, other.denominator);
+        let a = rest_a * (denom / self.denominator);
+        let b = rest_b * (denom / other.denominator);
+        let (carry, rest) = if a >= denom - b {
+            (T::one(), a - (denom - b))
+        } else {
+            (T::zero(), a + b)
+        };
+
+        // the midpoint is whole + (odd * denom + rest) / (2 * denom)
+        let (whole, odd) = (odd_a + odd_b + carry).div_mod_floor(&two);
+        let whole = half_a + half_b + whole;
+        let (numerator, denominator) = if odd.is_zero() && rest.is_even() {
+            reduce(rest / two, denom)
+        } else if !odd.is_zero() && rest.is_odd() == denom.is_odd() {
+            reduce(denom / two + rest / two + denom % two, denom)
+        } else {
+            // the numerator is odd here, so only the common factors of `rest` and `denom` cancel
+            let gcd = gcd(rest, denom);
+            (odd * (denom / gcd) + rest / gcd, two * (denom / gcd))
+        };
+
+        // the fractional part is proper, so a negative whole part is offset from above
+        let numerator = if whole.is_negative() {
+            (whole + T::one()) * denominator - (denominator - numerator)
+        } else {
+            whole * denominator + numerator
+        };
+
+        Fraction::<T> {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Returns the fraction with the smallest denominator lying strictly between `low` and `high`,
+    /// e.g. `2/5` between `1/3` and `1/2`.
+    ///
+    /// If several fractions share that denominator, the one closest to zero is chosen,
+    /// so an interval spanning an integer yields the integer with the smallest magnitude.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is not less than `high`.
+    pub fn simplest_between(low: &Self, high: &Self) -> Fraction<T> {
+        if low >= high {
+            panic!("Interval must not be empty");
+        }
+
+        if low.numerator.is_negative() && high.numerator.is_positive() {
+            return Fraction::from_valid_parts(T::zero(), T::one());
+        }
+
+        if !high.numerator.is_positive() {
+            let (numerator, denominator) = Fraction::simplest_between_impl(
+                (-high.numerator, high.denominator),
+                Some((-low.numerator, low.denominator)),
+            );
+
+            return Fraction::from_valid_parts(-numerator, denominator);
+        }
+
+        let (numerator, denominator) = Fraction::simplest_between_impl(
+            (low.numerator, low.denominator),
+            Some((high.numerator, high.denominator)),
+        );
+
+        Fraction::from_valid_parts(numerator, denominator)
+    }
+
+    /// Apportions `total` into integer shares proportional to the given non-negative fractions,
+    /// using the largest remainder method so that the shares always sum exactly to `total`.
+    ///
+    /// Every share is first rounded down (towards negative infinity, for a negative `total`),
+    /// and the units left over are handed out
+    /// to the shares with the largest remainders, earlier shares winning ties.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fractions sum to zero.
+    pub fn round_shares(fractions: &[Fraction<T>], total: T) -> Vec<T> {
+        let zero = Fraction::from_valid_parts(T::zero(), T::one());
+        let sum = fractions.iter().fold(zero, |acc, &f| acc + f);
+        if sum.numerator.is_zero() {
+            panic!("Shares must not sum to zero");
+        }
+
+        let total_frac = Fraction::from_valid_parts(total, T::one());
+        let quotas: Vec<(T, Fraction<T>)> = fractions
+            .iter()
+            .map(|&f| {
+                let quota = f * total_frac / sum;
+                let (whole, rest) = quota.numerator.div_mod_floor(&quota.denominator);
+
+                (whole, Fraction::from_valid_parts(rest, quota.denominator))
+            })
+            .collect();
+
+        let mut shares: Vec<T> = quotas.iter().map(|&(whole, _)| whole).collect();
+        let mut leftover = shares.iter().fold(total, |acc, &share| acc - share);
+
+        let mut by_remainder: Vec<usize> = (0..quotas.len()).collect();
+        by_remainder.sort_by(|&i, &j| quotas[j].1.cmp(&quotas[i].1));
+
+        for i in by_remainder {
+            if leftover.is_zero() {
+                break;
+            }
+
+            shares[i] = shares[i] + T::one();
+            leftover = leftover - T::one();
+        }
+
+        shares
+    }
+
+    /// Returns an iterator over `count` fractions,
+    /// starting at `start` and repeatedly adding `step` with exact arithmetic.
+    pub fn step_by(
+        start: Fraction<T>,
+        step: Fraction<T>,
+        count: usize,
+    ) -> impl Iterator<Item = Fraction<T>> {
+        let mut current = start;
+
+        (0..count).map(move |i| {
+            if i > 0 {
+                current += step;
+            }
+
+            current
+        })
+    }
+
+    /// Returns the closest fraction to this one
+    /// whose denominator does not exceed `max_den`.
+    ///
+    /// The approximation is found by walking the continued fraction expansion
+    /// and checking the best semiconvergent at the cut-off point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_den` is not positive.
+    pub fn approximate(&self, max_den: T) -> Fraction<T> {
+        if !max_den.is_positive() {
+            panic!("Maximum denominator must be positive");
+        }
+
+        // the convergents of a fraction never exceed its own parts
+        let convergents = walk_convergents(
+            complete_quotients(self.numerator, self.denominator),
+            max_den,
+            |(n, d): (T, T)| Some(n.div_floor(&d)),
+            convergent_numerator,
+        )
+        .expect("Convergents of a fraction fit in its backing type");
+
+        // n/d is the rest of the expansion [a_n+1; a_n+2, ...], and a_n+1 was cut off
+        let (n, d) = match convergents.cut {
+            Some(quotient) => quotient,
+            None => return *self,
+        };
+        let (a, rest) = n.div_mod_floor(&d);
+        let (k, semiconvergent) = convergents.semiconvergent(max_den, convergent_numerator);
+        let Convergents { q0, q1, .. } = convergents;
+
+        // the semiconvergent at exactly half the term is closer only if the fractional part
+        // of the rest is less than q_n-1/q_n, which `Ord` compares without overflowing
+        let semiconvergent_is_closer = k > a - k
+            || (k == a - k
+                && Fraction::<T> {
+                    numerator: rest,
+                    denominator: d,
+                } < Fraction::<T> {
+                    numerator: q0,
+                    denominator: q1,
+                });
+
+        match semiconvergent {
+            Some(semiconvergent) if semiconvergent_is_closer => semiconvergent,
+            _ => Fraction::<T> {
+                numerator: convergents.p1,
+                denominator: convergents.q1,
+            },
+        }
+    }
+
+    /// Replaces the fraction with its closest approximation
+    /// whose denominator does not exceed `max_den`, as found by `approximate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_den` is not positive.
+    pub fn clamp_denominator(&mut self, max_den: T) {
+        *self = self.approximate(max_den);
+    }
+
+    /// Returns an iterator lazily yielding the successive convergents
+    /// of the fraction's continued fraction expansion,
+    /// e.g. `415/93` yields `4/1, 9/2, 58/13, 415/93`.
+    ///
+    /// The last convergent is always equal to the fraction itself.
+    pub fn convergent_stream(&self) -> impl Iterator<Item = Fraction<T>> {
+        // (p0, q0, p1, q1, n, d): the last two convergents and the remaining fraction n/d
+        let step = |(p0, q0, p1, q1, n, d): (T, T, T, T, T, T)| {
+            let (a, rest) = n.div_mod_floor(&d);
+            (p1, q1, p0 + a * p1, q0 + a * q1, d, rest)
+        };
+
+        let first = step((
+            T::zero(),
+            T::one(),
+            T::one(),
+            T::zero(),
+            self.numerator,
+            self.denominator,
+        ));
+
+        iter::successors(Some(first), move |&state| {
+            let (_, _, _, _, _, d) = state;
+            if d.is_zero() {
+                None
+            } else {
+                Some(step(state))
+            }
+        })
+        .map(|(_, _, p, q, _, _)| Fraction::<T> {
+            numerator: p,
+            denominator: q,
+        })
+    }
+
+    /// Returns the closest fraction whose denominator does not exceed `max_den`,
+    /// provided it lies within `tolerance` of this fraction.
+    ///
+    /// The tolerance is applied to the approximation rather than to this fraction,
+    /// so its denominator never enters the arithmetic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_den` is not positive,
+    /// or if the approximation offset by `tolerance` in either direction overflows.
+    pub fn nearest_simple(&self, max_den: T, tolerance: Fraction<T>) -> Option<Fraction<T>> {
+        let approximation = self.approximate(max_den);
+
+        if approximation - tolerance <= *self && *self <= approximation + tolerance {
+            Some(approximation)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the Euclidean quotient of this fraction and `other` as an integer,
+    /// i.e. `q` such that `self == q * other + r` with `0 <= r < |other|`.
+    ///
+    /// For a positive `other` this is simply `floor(self / other)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is a zero.
+    pub fn div_euclid(&self, other: &Self) -> T {
+        let quotient = *self / *other;
+
+        if other.numerator.is_positive() {
+            quotient.numerator.div_floor(&quotient.denominator)
+        } else {
+            quotient.numerator.div_ceil(&quotient.denominator)
+        }
+    }
+
+    /// Returns the Euclidean remainder of this fraction and `other`,
+    /// which always lies in `[0, |other|)`,
+    /// e.g. `-1/2` modulo `1` is `1/2`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is a zero.
+    pub fn rem_euclid(&self, other: &Self) -> Fraction<T> {
+        *self - *other * self.div_euclid(other)
+    }
+
+    /// Returns the positive difference of this fraction and `other`,
+    /// i.e. `self - other` if it is positive and zero otherwise.
+    pub fn abs_sub(&self, other: &Self) -> Fraction<T> {
+        if self <= other {
+            Fraction::from_valid_parts(T::zero(), T::one())
+        } else {
+            *self - *other
+        }
+    }
+
+    /// Returns the sum of this fraction and `other`,
+    /// allowing chained arithmetic without operator imports.
+    pub fn added(self, other: Self) -> Self {
+        self + other
+    }
+
+    /// Returns the difference of this fraction and `other`.
+    pub fn subtracted(self, other: Self) -> Self {
+        self - other
+    }
+
+    /// Returns the product of this fraction and `other`.
+    pub fn multiplied(self, other: Self) -> Self {
+        self * other
+    }
+
+    /// Returns the quotient of this fraction and `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is a zero.
+    pub fn divided(self, other: Self) -> Self {
+        self / other
+    }
+}
+
+impl Fraction<i64> {
+    /// Returns the exact fraction represented by a finite `f64`.
+    ///
+    /// Every finite `f64` is a dyadic rational, i.e. `m * 2^e` for integers `m` and `e`,
+    /// so unlike approximation this conversion loses no information:
+    /// `0.1` becomes `3602879701896397/36028797018963968`, not `1/10`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not finite or its exact form does not fit in an `i64` fraction.
+    pub fn closest_float_fraction(value: f64) -> Fraction<i64> {
+        Fraction::from_f64_exact(value)
+            .expect("Float cannot be represented exactly as a 64-bit fraction")
+    }
+
+    /// Returns the closest fraction to a float whose denominator does not exceed `max_den`,
+    /// with an error explaining why the conversion failed otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_den` is not positive.
+    pub fn try_from_f64(value: f64, max_den: i64) -> Result<Fraction<i64>, FloatConversionError> {
+        if !value.is_finite() {
+            return Err(FloatConversionError::NotFinite);
+        }
+
+        Fraction::approximate_f64(value, max_den).ok_or(FloatConversionError::OutOfRange)
+    }
+
+    /// Returns the exact fraction represented by an `f32`,
+    /// or `None` if the value is not finite
+    /// or its exact form does not fit in an `i64` fraction
+    /// (e.g. very large values or subnormals).
+    pub fn from_exact_f32(value: f32) -> Option<Fraction<i64>> {
+        // widening to f64 is exact
+        Fraction::from_f64_exact(f64::from(value))
+    }
+
+    /// Returns the ratio `F(n+1)/F(n)` of consecutive Fibonacci numbers,
+    /// i.e. the `n`-th convergent of the golden ratio's continued fraction `[1; 1, 1, ...]`:
+    /// `1/1, 2/1, 3/2, 5/3, 8/5, ...`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0` (since `F(0) = 0`), or if `F(n+1)` overflows `i64`, i.e. for `n > 91`.
+    pub fn fibonacci_ratio(n: u32) -> Fraction<i64> {
+        assert!(n > 0, "Fibonacci ratio is undefined for n = 0");
+
+        let (mut previous, mut current) = (1i64, 1i64);
+        for _ in 1..n {
+            let next = previous
+                .checked_add(current)
+                .expect("Fibonacci number overflowed");
+            previous = current;
+            current = next;
+        }
+
+        // consecutive Fibonacci numbers are always coprime
+        Fraction {
+            numerator: current,
+            denominator: previous,
+        }
+    }
+
+    /// Converts the fraction to the `f64` nearest to its exact value,
+    /// rounding ties to even.
+    ///
+    /// Unlike the `From` conversion, which divides two already rounded floats,
+    /// this performs the division on the integer parts and rounds only once.
+    pub fn to_f64_correct(&self) -> f64 {
+        if self.numerator == 0 {
+            return 0.0;
+        }
+
+        let n = u128::from(self.numerator.unsigned_abs());
+        let d = u128::from(self.denominator.unsigned_abs());
+
+        // scale the division so that the quotient has 55 or 56 significant bits
+        let shift = 55 + n.leading_zeros() as i32 - d.leading_zeros() as i32;
+        let (scaled_n, scaled_d) = if shift >= 0 {
+            (n << shift, d)
+        } else {
+            (n, d << -shift)
+        };
+
+        let quotient = scaled_n / scaled_d;
+        let sticky = scaled_n % scaled_d != 0;
+
+        let extra = 128 - quotient.leading_zeros() as i32 - 53;
+        let mut mantissa = quotient >> extra;
+        let dropped = quotient & ((1 << extra) - 1);
+        let half = 1 << (extra - 1);
+        if dropped > half || (dropped == half && (sticky || mantissa & 1 == 1)) {
+            mantissa += 1;
+        }
+
+        let exponent = extra - shift;
+        let scale = f64::from_bits(((exponent + 1023) as u64) << 52);
+        let magnitude = mantissa as f64 * scale;
+
+        if self.numerator < 0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl<T: FromStr + Integer + Signed + Copy + CheckedAdd + CheckedSub + CheckedMul> Fraction<T> {
+    /// Parses a fraction written either as a ratio (`"3/4"`),
+    /// a decimal (`"0.75"`) or a percentage (`"75%"`).
+    ///
+    /// The result is always exact and reduced.
+    /// A decimal or percentage whose exact value does not fit in the backing type
+    /// is reported as an overflow.
+    pub fn parse_flexible(s: &str) -> Result<Fraction<T>, FractionParseError<T::Err>> {
+        if s.contains('/') {
+            let f = s.parse::<Fraction<T>>()?;
+            return Ok(Fraction::from_valid_parts(f.numerator, f.denominator));
+        }
+
+        match s.strip_suffix('%') {
+            Some(percentage) => Fraction::from_decimal_str(percentage, 2),
+            None => Fraction::from_decimal_str(s, 0),
+        }
+    }
+
+    /// Parses a fraction whose parts are separated by `sep` instead of `/`,
+    /// e.g. `"16:9"` with `':'`. The result is reduced.
+    pub fn parse_with_separator(
+        s: &str,
+        sep: char,
+    ) -> Result<Fraction<T>, FractionParseError<T::Err>> {
+        let (numerator, denominator) = parse_parts(s, sep)?;
+
+        Ok(Fraction::from_valid_parts(numerator, denominator))
+    }
+
+    /// Parses a list of fractions separated by `delimiter`, e.g. `"1/2;3/4;5/6"` with `';'`.
+    ///
+    /// Whitespace around each element is ignored, and every fraction is reduced.
+    /// The first malformed element aborts parsing with its error.
+    pub fn parse_delimited(
+        s: &str,
+        delimiter: char,
+    ) -> Result<Vec<Fraction<T>>, FractionParseError<T::Err>> {
+        s.split(delimiter)
+            .map(|element| {
+                let f = element.trim().parse::<Fraction<T>>()?;
+                Ok(Fraction::from_valid_parts(f.numerator, f.denominator))
+            })
+            .collect()
+    }
+
+    /// Parses a decimal number, additionally dividing it by `10^shift`.
+    ///
+    /// The digits after the decimal point are folded in from the last one,
+    /// dividing by ten and reducing at every step, so that intermediate values
+    /// only overflow if the exact result does not fit in the backing type either.
+    fn from_decimal_str(s: &str, shift: usize) -> Result<Fraction<T>, FractionParseError<T::Err>> {
+        let (integral, fractional) = match s.split('.').collect_tuple() {
+            Some((integral, fractional)) => (integral, fractional),
+            None if !s.contains('.') => (s, ""),
+            None => return Err(FractionParseError::IncorrectForm),
+        };
+
+        if !fractional.chars().all(|c| c.is_ascii_digit()) {
+            return Err(FractionParseError::IncorrectForm);
+        }
+
+        let parse = |unparsed: &str| {
+            T::from_str(unparsed)
+                .map_err(|err| FractionParseError::in_component(Component::Numerator, s, err))
+        };
+
+        let negative = integral.starts_with('-');
+        let whole = match integral {
+            "" | "-" | "+" if !fractional.is_empty() => T::zero(),
+            _ => parse(integral)?,
+        };
+
+        let ten = parse("10")?;
+        let divide_by_ten = |(numerator, denominator): (T, T)| {
+            let gcd = gcd(numerator, ten);
+            Some((numerator / gcd, denominator.checked_mul(&(ten / gcd))?))
+        };
+
+        let mut rest = (T::zero(), T::one());
+        for digit in fractional.chars().rev() {
+            let digit = parse(digit.encode_utf8(&mut [0; 4]))?;
+            let (numerator, denominator) = rest;
+            let numerator = digit
+                .checked_mul(&denominator)
+                .and_then(|scaled| numerator.checked_add(&scaled))
+                .ok_or(FractionParseError::Overflow)?;
+
+            rest = divide_by_ten((numerator, denominator)).ok_or(FractionParseError::Overflow)?;
+        }
+
+        let (rest_numerator, denominator) = rest;
+        let scaled_whole = whole
+            .checked_mul(&denominator)
+            .ok_or(FractionParseError::Overflow)?;
+        let numerator = if negative {
+            scaled_whole.checked_sub(&rest_numerator)
+        } else {
+            scaled_whole.checked_add(&rest_numerator)
+        }
+        .ok_or(FractionParseError::Overflow)?;
+
+        let mut result = (numerator, denominator);
+        for _ in 0..shift {
+            result = divide_by_ten(result).ok_or(FractionParseError::Overflow)?;
+        }
+
+        Ok(Fraction::from_valid_parts(result.0, result.1))
+    }
+}
+
+impl<T: Integer + Signed + Copy + CheckedAdd + CheckedSub + CheckedMul> Fraction<T> {
+    /// Adds two fractions, returning an error instead of overflowing.
+    pub fn try_add(&self, other: &Self) -> Result<Fraction<T>, ArithmeticError> {
+        self.checked_add_impl(other)
+            .map(|(numerator, denominator)| Fraction::<T> {
+                numerator,
+                denominator,
+            })
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Subtracts two fractions, returning an error instead of overflowing.
+    pub fn try_sub(&self, other: &Self) -> Result<Fraction<T>, ArithmeticError> {
+        self.checked_sub_impl(other)
+            .map(|(numerator, denominator)| Fraction::<T> {
+                numerator,
+                denominator,
+            })
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Multiplies two fractions, returning an error instead of overflowing.
+    pub fn try_mul(&self, other: &Self) -> Result<Fraction<T>, ArithmeticError> {
+        self.checked_mul_impl(other)
+            .map(|(numerator, denominator)| Fraction::<T> {
+                numerator,
+                denominator,
+            })
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Divides two fractions, returning an error
+    /// if `other` is a zero or the result overflows.
+    ///
+    /// The error tells a zero divisor (`ArithmeticError::DivisionByZero`)
+    /// apart from an overflow (`ArithmeticError::Overflow`).
+    #[doc(alias = "checked_div")]
+    pub fn try_div(&self, other: &Self) -> Result<Fraction<T>, ArithmeticError> {
+        if other.numerator.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        self.checked_div_impl(other)
+            .map(|(numerator, denominator)| Fraction::<T> {
+                numerator,
+                denominator,
+            })
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Adds two fractions, returning `None` instead of overflowing.
+    pub fn checked_add(&self, other: &Self) -> Option<Fraction<T>> {
+        self.try_add(other).ok()
+    }
+
+    /// Subtracts two fractions, returning `None` instead of overflowing,
+    /// e.g. when the difference reaches past `T::MIN`.
+    pub fn checked_sub(&self, other: &Self) -> Option<Fraction<T>> {
+        self.try_sub(other).ok()
+    }
+
+    /// Sums all fractions, returning `None` at the first overflow.
+    /// An empty slice sums to zero.
+    pub fn checked_sum(fracs: &[Fraction<T>]) -> Option<Fraction<T>> {
+        fracs
+            .iter()
+            .try_fold(Fraction::from_valid_parts(T::zero(), T::one()), |acc, f| {
+                acc.checked_add(f)
+            })
+    }
+
+    /// Multiplies the fraction by an integer, returning `None` instead of overflowing.
+    ///
+    /// The scalar is cross-reduced against the denominator before multiplying.
+    pub fn checked_mul_int(&self, scalar: T) -> Option<Fraction<T>> {
+        let gcd = gcd(scalar, self.denominator);
+
+        Some(Fraction::<T> {
+            numerator: self.numerator.checked_mul(&(scalar / gcd))?,
+            denominator: self.denominator / gcd,
+        })
+    }
+
+    /// Adds an integer to the fraction, returning `None` instead of overflowing.
+    pub fn checked_add_int(&self, scalar: T) -> Option<Fraction<T>> {
+        // n/d + k = (n + k*d)/d, which stays reduced
+        let scaled = scalar.checked_mul(&self.denominator)?;
+
+        Some(Fraction::<T> {
+            numerator: self.numerator.checked_add(&scaled)?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Subtracts an integer from the fraction, returning `None` instead of overflowing.
+    pub fn checked_sub_int(&self, scalar: T) -> Option<Fraction<T>> {
+        let scaled = scalar.checked_mul(&self.denominator)?;
+
+        Some(Fraction::<T> {
+            numerator: self.numerator.checked_sub(&scaled)?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Divides the fraction by an integer,
+    /// returning `None` if the scalar is zero or the result overflows.
+    ///
+    /// The scalar is cross-reduced against the numerator before multiplying.
+    pub fn checked_div_int(&self, scalar: T) -> Option<Fraction<T>> {
+        if scalar.is_zero() {
+            return None;
+        }
+
+        if self.numerator.is_zero() {
+            return Some(*self);
+        }
+
+        // the gcd of two equal values could be `|T::MIN|`, which does not fit
+        let gcd = if self.numerator == scalar {
+            scalar
+        } else {
+            gcd(self.numerator, scalar)
+        };
+        let numerator = self.numerator / gcd;
+        let denominator = self.denominator.checked_mul(&(scalar / gcd))?;
+
+        if denominator.is_negative() {
+            return Some(Fraction::<T> {
+                numerator: T::zero().checked_sub(&numerator)?,
+                denominator: T::zero().checked_sub(&denominator)?,
+            });
+        }
+
+        Some(Fraction::<T> {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Negates the fraction, or returns `None` if the numerator is `T::MIN` and cannot be negated.
+    pub fn checked_neg(&self) -> Option<Fraction<T>> {
+        Some(Fraction::<T> {
+            numerator: T::zero().checked_sub(&self.numerator)?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Returns the absolute value of the fraction,
+    /// or `None` if the numerator is `T::MIN` and cannot be negated.
+    pub fn checked_abs(&self) -> Option<Fraction<T>> {
+        if !self.numerator.is_negative() {
+            return Some(*self);
+        }
+
+        Some(Fraction::<T> {
+            numerator: T::zero().checked_sub(&self.numerator)?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Returns the reciprocal of the fraction,
+    /// or `None` if the fraction is a zero or the sign normalization overflows.
+    pub fn checked_reciprocal(&self) -> Option<Fraction<T>> {
+        if self.numerator.is_zero() {
+            return None;
+        }
+
+        if self.numerator.is_negative() {
+            return Some(Fraction::<T> {
+                numerator: T::zero().checked_sub(&self.denominator)?,
+                denominator: T::zero().checked_sub(&self.numerator)?,
+            });
+        }
+
+        Some(Fraction::<T> {
+            numerator: self.denominator,
+            denominator: self.numerator,
+        })
+    }
+
+    /// Returns an iterator lazily yielding the successive powers `1, x, x^2, ...` of the fraction.
+    ///
+    /// The iterator ends before the first power that would overflow,
+    /// and is infinite if no power ever does (e.g. for `0`, `1` or `-1`).
+    pub fn powers(&self) -> impl Iterator<Item = Fraction<T>> {
+        let x = *self;
+
+        iter::successors(
+            Some(Fraction::from_valid_parts(T::one(), T::one())),
+            move |power| power.try_mul(&x).ok(),
+        )
+    }
+
+    /// Evaluates the polynomial `c0 + c1*x + c2*x^2 + ...` exactly, using Horner's method.
+    /// An empty list of coefficients evaluates to zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any intermediate result overflows.
+    pub fn eval_polynomial(coeffs: &[Fraction<T>], x: Fraction<T>) -> Fraction<T> {
+        coeffs
+            .iter()
+            .rev()
+            .fold(Fraction::from_valid_parts(T::zero(), T::one()), |acc, c| {
+                acc.try_mul(&x)
+                    .and_then(|scaled| scaled.try_add(c))
+                    .expect("Polynomial evaluation overflowed")
+            })
+    }
+
+    /// Raises the fraction to an integer power, which can also be negative.
+    ///
+    /// Returns `None` if any step overflows,
+    /// or if a zero is raised to a negative power.
+    pub fn checked_pow(&self, exp: i32) -> Option<Fraction<T>> {
+        if exp < 0 {
+            return self
+                .checked_reciprocal()?
+                .checked_pow_unsigned(exp.unsigned_abs() as usize);
+        }
+
+        self.checked_pow_unsigned(exp as usize)
+    }
+
+    fn checked_pow_unsigned(&self, exp: usize) -> Option<Fraction<T>> {
+        // powers of coprime integers stay coprime, so no reduction is needed
+        Some(Fraction::<T> {
+            numerator: checked_pow(self.numerator, exp)?,
+            denominator: checked_pow(self.denominator, exp)?,
+        })
+    }
+
+    /// Computes the telescoping series `1/(1*2) + 1/(2*3) + ... + 1/(n*(n+1))` term by term,
+    /// which equals `n/(n+1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any intermediate result overflows.
+    pub fn sum_reciprocals_product(n: T) -> Fraction<T> {
+        let mut sum = Fraction::from_valid_parts(T::zero(), T::one());
+
+        let mut k = T::one();
+        while k <= n {
+            let denominator = k
+                .checked_mul(&(k + T::one()))
+                .expect("Series term overflowed");
+            let term = Fraction::from_valid_parts(T::one(), denominator);
+
+            sum = sum.try_add(&term).expect("Series sum overflowed");
+            k = k + T::one();
+        }
+
+        sum
+    }
+
+    /// Sums fractions over a fixed denominator without reducing the result,
+    /// e.g. `1/4 + 1/2` over `100` gives `(75, 100)`.
+    ///
+    /// Returns `None` if the denominator is not positive,
+    /// if some fraction cannot be represented over it, or if the sum overflows.
+    pub fn sum_over(fracs: &[Fraction<T>], denominator: T) -> Option<(T, T)> {
+        if !denominator.is_positive() {
+            return None;
+        }
+
+        let mut numerator = T::zero();
+        for f in fracs {
+            let (scale, rest) = denominator.div_rem(&f.denominator);
+            if !rest.is_zero() {
+                return None;
+            }
+
+            numerator = numerator.checked_add(&f.numerator.checked_mul(&scale)?)?;
+        }
+
+        Some((numerator, denominator))
+    }
+
+    /// Sums fractions over the least common multiple of their denominators,
+    /// e.g. `1/4 + 1/6` gives `(5, 12)`.
+    ///
+    /// The numerator is kept on that denominator, even if the sum could be reduced further.
+    /// Returns `None` if the common denominator or the sum overflows.
+    pub fn checked_sum_lcm(fracs: &[Fraction<T>]) -> Option<(T, T)> {
+        let denominator = fracs.iter().try_fold(T::one(), |acc, f| {
+            let gcd = gcd(acc, f.denominator);
+            (acc / gcd).checked_mul(&f.denominator)
+        })?;
+
+        Fraction::sum_over(fracs, denominator)
+    }
+
+    /// Computes the least common multiple of the denominators of all fractions,
+    /// folding them lazily. An empty input yields `1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the common multiple overflows,
+    /// since no smaller value would be a valid common denominator.
+    pub fn lcm_denominators<I: IntoIterator<Item = Fraction<T>>>(iter: I) -> T {
+        iter.into_iter().fold(T::one(), |acc, f| {
+            let gcd = gcd(acc, f.denominator);
+
+            (acc / gcd)
+                .checked_mul(&f.denominator)
+                .expect("Common denominator overflowed")
+        })
+    }
+
+    /// Rescales every fraction to the least common multiple of all denominators,
+    /// e.g. `[1/2, 1/3]` becomes `[(3, 6), (2, 6)]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the common denominator or any rescaled numerator overflows.
+    pub fn align_to_lcm(fracs: &[Fraction<T>]) -> Vec<(T, T)> {
+        let denominator = Fraction::lcm_denominators(fracs.iter().copied());
+
+        fracs
+            .iter()
+            .map(|f| {
+                let numerator = f
+                    .numerator
+                    .checked_mul(&(denominator / f.denominator))
+                    .expect("Rescaled numerator overflowed");
+
+                (numerator, denominator)
+            })
+            .collect()
+    }
+
+    /// Converts a `Ratio` into a fraction,
+    /// returning `None` if its denominator is zero
+    /// or if normalizing its sign would overflow.
+    pub fn try_from_ratio(r: Ratio<T>) -> Option<Fraction<T>> {
+        let (numerator, denominator) = (*r.numer(), *r.denom());
+
+        if denominator.is_zero() {
+            return None;
+        }
+
+        if numerator == denominator {
+            return Some(Fraction::from_valid_parts(T::one(), T::one()));
+        }
+
+        if numerator.is_zero() {
+            return Some(Fraction::from_valid_parts(T::zero(), T::one()));
+        }
+
+        let gcd = gcd(numerator, denominator);
+        let (numerator, denominator) = (numerator / gcd, denominator / gcd);
+
+        if denominator.is_negative() {
+            return Some(Fraction::<T> {
+                numerator: T::zero().checked_sub(&numerator)?,
+                denominator: T::zero().checked_sub(&denominator)?,
+            });
+        }
+
+        Some(Fraction::<T> {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Returns an iterator over every fraction in the inclusive range `[low, high]`
+    /// whose denominator does not exceed `max_den`, in ascending order.
+    ///
+    /// Every value is yielded exactly once, in its reduced form.
+    /// Fractions whose numerator does not fit in `T` are skipped,
+    /// e.g. on `Fraction16` the range `[1000, 1001]` only yields denominators up to `32`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_den` is not positive.
+    pub fn range_with_max_denominator(
+        low: Fraction<T>,
+        high: Fraction<T>,
+        max_den: T,
+    ) -> impl Iterator<Item = Fraction<T>> {
+        if !max_den.is_positive() {
+            panic!("Maximum denominator must be positive");
+        }
+
+        let first = if low.denominator <= max_den {
+            Some(low)
+        } else {
+            Fraction::closest_above(&low, max_den)
+        };
+
+        iter::successors(first, move |current| {
+            if *current >= high {
+                None
+            } else {
+                Fraction::closest_above(current, max_den)
+            }
+        })
+        .take_while(move |current| *current <= high)
+    }
+
+    /// Creates fractions from `(numerator, denominator)` pairs, e.g. rows of a CSV column.
+    ///
+    /// Every pair is normalized and reduced the same way `new` does it,
+    /// but the first pair with a zero denominator,
+    /// or one whose normalized form does not fit in the backing type (e.g. `T::MIN/-1`),
+    /// is reported along with its index instead.
+    pub fn from_pairs_checked<I: IntoIterator<Item = (T, T)>>(
+        pairs: I,
+    ) -> Result<Vec<Fraction<T>>, (usize, ConstructionError)> {
+        pairs
+            .into_iter()
+            .enumerate()
+            .map(|(index, (numerator, denominator))| {
+                if denominator.is_zero() {
+                    return Err((index, ConstructionError::ZeroDenominator));
+                }
+
+                Fraction::try_from_ratio(Ratio::new_raw(numerator, denominator))
+                    .ok_or((index, ConstructionError::Overflow))
+            })
+            .collect()
+    }
+
+    /// Finds the smallest fraction greater than the bound
+    /// whose denominator does not exceed `max_den` and whose numerator fits in `T`.
+    ///
+    /// The convergents of the bound are bounded by its own parts,
+    /// so only extending past a bound that qualifies itself needs checked arithmetic.
+    fn closest_above(bound: &Self, max_den: T) -> Option<Fraction<T>> {
+        let convergents = walk_convergents(
+            complete_quotients(bound.numerator, bound.denominator),
+            max_den,
+            |(n, d): (T, T)| Some(n.div_floor(&d)),
+            convergent_numerator,
+        )
+        .expect("Convergents of a fraction fit in its backing type");
+        let Convergents { p0, q0, p1, q1, .. } = convergents;
+
+        if convergents.cut.is_some() {
+            // the convergent and the largest semiconvergent are the neighbours of the bound
+            // in the Farey sequence of order `max_den`, one on either side
+            let convergent = Fraction::<T> {
+                numerator: p1,
+                denominator: q1,
+            };
+
+            if convergent > *bound {
+                return Some(convergent);
+            }
+
+            return convergents.semiconvergent(max_den, convergent_numerator).1;
+        }
+
+        // the bound qualifies itself, so its upper neighbour among smaller denominators,
+        // either p0/q0 or (p1 - p0)/(q1 - q0), is extended by as many copies of it as fit;
+        // above a whole bound that neighbour is 1/0, so the next whole number is taken instead
+        let previous = Fraction::<T> {
+            numerator: p0,
+            denominator: q0,
+        };
+        let (numerator, denominator) = if q0.is_zero() {
+            (p1.checked_add(&T::one())?, T::one())
+        } else if previous > *bound {
+            (p0, q0)
+        } else {
+            (p1 - p0, q1 - q0)
+        };
+
+        // the neighbour has the same sign as the bound, so a product that overflows
+        // would also make the sum overflow
+        let fits = |j: T| {
+            j.checked_mul(&p1)
+                .and_then(|step| step.checked_add(&numerator))
+                .is_some()
+        };
+
+        let mut low = T::zero();
+        let mut high = (max_den - denominator) / q1;
+        let two = T::one() + T::one();
+        while low < high {
+            let mid = high - (high - low) / two;
+            if fits(mid) {
+                low = mid;
+            } else {
+                high = mid - T::one();
+            }
+        }
+
+        Some(Fraction::<T> {
+            numerator: numerator + low * p1,
+            denominator: denominator + low * q1,
+        })
+    }
+
+    fn checked_sub_impl(&self, other: &Self) -> Option<(T, T)> {
+        let gcd = gcd(self.denominator, other.denominator);
+        let denom = (self.denominator / gcd).checked_mul(&other.denominator)?;
+        let a = self.numerator.checked_mul(&(denom / self.denominator))?;
+        let b = other.numerator.checked_mul(&(denom / other.denominator))?;
+
+        Some(reduce(a.checked_sub(&b)?, denom))
+    }
+
+    fn checked_div_impl(&self, other: &Self) -> Option<(T, T)> {
+        if self.numerator.is_zero() {
+            return Some((T::zero(), T::one()));
+        }
+
+        // the gcd of two equal numerators could be `|T::MIN|`, which does not fit
+        let gcd_ac = if self.numerator == other.numerator {
+            self.numerator
+        } else {
+            gcd(self.numerator, other.numerator)
+        };
+        let gcd_db = gcd(other.denominator, self.denominator);
+
+        let mut a = self.numerator / gcd_ac;
+        let mut c = other.numerator / gcd_ac;
+
+        // move the divisor's sign onto the numerator before multiplying,
+        // so that a result with a numerator of `T::MIN` is not rejected
+        if c.is_negative() {
+            a = T::zero().checked_sub(&a)?;
+            c = T::zero().checked_sub(&c)?;
+        }
+
+        let numerator = a.checked_mul(&(other.denominator / gcd_db))?;
+        let denominator = (self.denominator / gcd_db).checked_mul(&c)?;
+
+        Some((numerator, denominator))
+    }
+}
+
+impl<T: Integer + Copy + CheckedAdd + CheckedMul> Fraction<T> {
+    fn checked_add_impl(&self, other: &Self) -> Option<(T, T)> {
+        let gcd = gcd(self.denominator, other.denominator);
+        let denom = (self.denominator / gcd).checked_mul(&other.denominator)?;
+        let a = self.numerator.checked_mul(&(denom / self.denominator))?;
+        let b = other.numerator.checked_mul(&(denom / other.denominator))?;
+
+        Some(reduce(a.checked_add(&b)?, denom))
+    }
+
+    // a partial sum of whole and proper fractional parts, used by `Sum`
+    fn checked_add_mixed(&self, other: &Self) -> Option<(T, T)> {
+        let (whole_a, rest_a) = self.numerator.div_mod_floor(&self.denominator);
+        let (whole_b, rest_b) = other.numerator.div_mod_floor(&other.denominator);
+
+        // the fractional parts scaled to the common denominator are both less than it,
+        // so their sum is split into a carry and a remainder without overflowing
+        let gcd = gcd(self.denominator, other.denominator);
+        let denom = (self.denominator / gcd).checked_mul(&other.denominator)?;
+        let a = rest_a * (denom / self.denominator);
+        let b = rest_b * (denom / other.denominator);
+        let (carry, rest) = if a >= denom - b {
+            (T::one(), a - (denom - b))
+        } else {
+            (T::zero(), a + b)
+        };
+
+        let whole = whole_a.checked_add(&whole_b)?.checked_add(&carry)?;
+        let (numerator, denominator) = reduce(rest, denom);
+
+        // the fractional part is proper, so a negative whole part is offset from above
+        let numerator = if whole < T::zero() {
+            (whole + T::one())
+                .checked_mul(&denominator)?
+                .checked_add(&(numerator - denominator))?
+        } else {
+            whole.checked_mul(&denominator)?.checked_add(&numerator)?
+        };
+
+        Some((numerator, denominator))
+    }
+
+    fn checked_mul_impl(&self, other: &Self) -> Option<(T, T)> {
+        // cross-reducing keeps the result reduced without another gcd pass
+        let gcd_ad = gcd(self.numerator, other.denominator);
+        let gcd_cb = gcd(other.numerator, self.denominator);
+
+        let numerator = (self.numerator / gcd_ad).checked_mul(&(other.numerator / gcd_cb))?;
+        let denominator = (self.denominator / gcd_cb).checked_mul(&(other.denominator / gcd_ad))?;
+
+        if numerator.is_zero() {
+            return Some((T::zero(), T::one()));
+        }
+
+        Some((numerator, denominator))
+    }
+}
+
+impl<T: Integer + Signed + Copy + FromPrimitive + ToPrimitive> Fraction<T> {
+    /// Converts the fraction to an `f64`,
+    /// returning `None` instead of an infinite or NaN result.
+    ///
+    /// Primitive backing types never produce a non-finite result,
+    /// since even 128-bit parts are well within the `f64` range,
+    /// so `None` only comes from custom integer types whose conversion overflows.
+    pub fn to_f64_finite(&self) -> Option<f64> {
+        self.to_f64().filter(|value| value.is_finite())
+    }
+
+    /// Sorts fractions in ascending order, comparing cheap `f64` approximations first
+    /// and falling back to exact comparison only where those are not conclusive.
+    ///
+    /// The values are decorated with their float keys and sorted by them, with ties broken exactly.
+    /// Since rounding can also misorder distinct values with nearly equal keys,
+    /// a final insertion pass with exact comparisons fixes up the result,
+    /// which takes linear time when the keys were accurate.
+    pub fn sort_fractions(v: &mut [Fraction<T>]) {
+        let mut keyed: Vec<(f64, Fraction<T>)> = v
+            .iter()
+            .map(|f| (f.to_f64().unwrap_or(f64::NAN), *f))
+            .collect();
+
+        keyed.sort_by(|(key_a, a), (key_b, b)| key_a.total_cmp(key_b).then_with(|| a.cmp(b)));
+
+        for (slot, (_, f)) in v.iter_mut().zip(keyed) {
+            *slot = f;
+        }
+
+        for i in 1..v.len() {
+            let mut j = i;
+            while j > 0 && v[j - 1] > v[j] {
+                v.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+
+    /// Returns the numerator and denominator converted to floats, without dividing them,
+    /// e.g. `3/4` becomes `(3.0, 4.0)`.
+    pub fn as_f64_pair(&self) -> (f64, f64) {
+        let numerator = self
+            .numerator
+            .to_f64()
+            .expect("Numerator is not convertible to f64");
+        let denominator = self
+            .denominator
+            .to_f64()
+            .expect("Denominator is not convertible to f64");
+
+        (numerator, denominator)
+    }
+
+    /// Renders the fraction with both numerator and denominator in the given radix,
+    /// e.g. `255/16` in radix 16 becomes `"ff/10"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        format!(
+            "{}/{}",
+            int_to_string_radix(self.numerator, radix),
+            int_to_string_radix(self.denominator, radix)
+        )
+    }
+
+    /// Returns an iterator over the decimal digits of the fraction's magnitude:
+    /// first the digits of the integer part, then the digits after the decimal point,
+    /// e.g. `1/7` yields `0, 1, 4, 2, 8, 5, 7, 1, ...`.
+    ///
+    /// The iterator ends once the expansion terminates,
+    /// and is infinite for repeating expansions.
+    pub fn decimal_digits(&self) -> impl Iterator<Item = u8> {
+        let (whole, rest) = self.to_mixed();
+        let denominator = rest.denominator;
+
+        let whole_digits: Vec<u8> = int_to_string_radix(whole, 10)
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(|digit| digit - b'0')
+            .collect();
+
+        // the remainder is smaller than the denominator, so its magnitude always fits
+        let mut remainder = abs(rest.numerator);
+        let fractional_digits = iter::from_fn(move || {
+            if remainder.is_zero() {
+                return None;
+            }
+
+            Some(Fraction::next_decimal_digit(&mut remainder, denominator))
+        });
+
+        whole_digits.into_iter().chain(fractional_digits)
+    }
+
+    /// Returns the closest fraction to π whose denominator does not exceed `max_den`,
+    /// e.g. `22/7` for `10` and `355/113` for `120`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_den` is not positive, if the approximation does not fit in the backing type,
+    /// or if `max_den` is so large that more terms of the expansion of π would be needed
+    /// than are known to this crate (which only happens for 128-bit backings).
+    pub fn approximate_pi(max_den: T) -> Fraction<T> {
+        Fraction::approximate_from_terms(PI_TERMS.iter().copied(), max_den)
+            .expect("Not enough terms of pi are known for this bound")
+    }
+
+    /// Returns the closest fraction to e whose denominator does not exceed `max_den`,
+    /// e.g. `19/7` for `10` and `193/71` for `100`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_den` is not positive or if the approximation does not fit in the backing type.
+    pub fn approximate_e(max_den: T) -> Fraction<T> {
+        // e = [2; 1, 2, 1, 1, 4, 1, 1, 6, 1, ...]
+        let terms = iter::once(2).chain((1..).flat_map(|k| vec![1, 2 * k, 1]));
+
+        Fraction::approximate_from_terms(terms, max_den).expect("Expansion of e is infinite")
+    }
+
+    /// Finds the closest fraction whose denominator does not exceed `max_den`
+    /// to the irrational number with the given continued fraction terms.
+    ///
+    /// Returns `None` if the terms run out before the answer is known.
+    fn approximate_from_terms<I: Iterator<Item = u32>>(
+        mut terms: I,
+        max_den: T,
+    ) -> Option<Fraction<T>> {
+        if !max_den.is_positive() {
+            panic!("Maximum denominator must be positive");
+        }
+
+        let convergents =
+            walk_convergents(terms.by_ref(), max_den, T::from_u32, convergent_numerator)?;
+        let a = u64::from(convergents.cut?);
+        let (k, semiconvergent) = convergents.semiconvergent(max_den, convergent_numerator);
+        let double_k = 2 * k.to_u64()?;
+
+        // the semiconvergent at exactly half the term is closer only if the rest of the expansion
+        // [a_n+1; a_n+2, ...] exceeds q_n-1/q_n-2, i.e. if it is closer to the convergent's side
+        let Convergents { p1, q0, q1, .. } = convergents;
+        let semiconvergent_is_closer = double_k > a
+            || (double_k == a && !q0.is_zero() && expansion_exceeds(&mut terms, q1, q0)?);
+
+        if semiconvergent_is_closer {
+            semiconvergent
+        } else {
+            Some(Fraction::<T> {
+                numerator: p1,
+                denominator: q1,
+            })
+        }
+    }
+}
+
+impl<T> Fraction<T>
+where
+    T: Integer + Signed + Copy + CheckedAdd + CheckedMul + FromPrimitive + ToPrimitive,
+{
+    /// Approximates `base` raised to the fractional power `exp`,
+    /// returning the closest fraction whose denominator does not exceed `max_den`.
+    ///
+    /// The power is evaluated in `f64` and then snapped to a fraction,
+    /// so the result is only exact when the true power is a simple fraction itself.
+    /// Returns `None` for even roots of negative numbers,
+    /// non-finite results and results that do not fit in the backing type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_den` is not positive.
+    pub fn int_pow_frac(base: T, exp: Fraction<T>, max_den: T) -> Option<Fraction<T>> {
+        let p = exp.numerator.to_f64()?;
+        let q = exp.denominator.to_f64()?;
+        let magnitude = base.abs().to_f64()?.powf(p / q);
+
+        let value = if base.is_negative() {
+            if exp.denominator.is_even() {
+                return None;
+            }
+
+            if exp.numerator.is_odd() {
+                -magnitude
+            } else {
+                magnitude
+            }
+        } else {
+            magnitude
+        };
+
+        Fraction::approximate_f64(value, max_den)
+    }
+
+    /// Finds the closest fraction to a float
+    /// whose denominator does not exceed `max_den`,
+    /// or `None` if the value is not finite or the fraction does not fit.
+    fn approximate_f64(value: f64, max_den: T) -> Option<Fraction<T>> {
+        if !max_den.is_positive() {
+            panic!("Maximum denominator must be positive");
+        }
+
+        if !value.is_finite() {
+            return None;
+        }
+
+        let quotients = iter::successors(Some(value), |&x| {
+            let rest = x - x.floor();
+            if rest == 0.0 {
+                None
+            } else {
+                Some(1.0 / rest)
+            }
+        });
+
+        let convergents = walk_convergents(
+            quotients,
+            max_den,
+            |x: f64| T::from_f64(x.floor()),
+            checked_convergent_numerator,
+        )?;
+
+        // x is the rest of the expansion [a_n+1; a_n+2, ...], and a_n+1 was cut off
+        let x = match convergents.cut {
+            Some(x) => x,
+            None => {
+                return Some(Fraction::<T> {
+                    numerator: convergents.p1,
+                    denominator: convergents.q1,
+                })
+            }
+        };
+        let a = x.floor();
+        let (k, semiconvergent) = convergents.semiconvergent(max_den, checked_convergent_numerator);
+        let double_k = 2.0 * k.to_f64()?;
+
+        // the semiconvergent at exactly half the term is closer only if the fractional part
+        // of the rest is less than q_n-1/q_n
+        let Convergents { p1, q0, q1, .. } = convergents;
+        let semiconvergent_is_closer =
+            double_k > a || (double_k == a && x - a < q0.to_f64()? / q1.to_f64()?);
+
+        if semiconvergent_is_closer {
+            semiconvergent
+        } else {
+            Some(Fraction::<T> {
+                numerator: p1,
+                denominator: q1,
+            })
+        }
+    }
+}
+
+impl<T: Integer + Signed + Copy + ToBytes> Fraction<T> {
+    /// Serializes the fraction as the little-endian bytes of its numerator,
+    /// followed by the little-endian bytes of its denominator.
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 * mem::size_of::<T>());
+        bytes.extend_from_slice(self.numerator.to_le_bytes().as_ref());
+        bytes.extend_from_slice(self.denominator.to_le_bytes().as_ref());
+
+        bytes
+    }
+}
+
+impl<T> Fraction<T>
+where
+    T: Integer + Signed + Copy + FromBytes,
+    for<'a> &'a T::Bytes: TryFrom<&'a [u8]>,
+{
+    /// Deserializes a fraction written by [`to_le_bytes`](Fraction::to_le_bytes).
+    ///
+    /// Returns `None` if the slice has the wrong length
+    /// or the encoded denominator is zero.
+    pub fn from_le_bytes(bytes: &[u8]) -> Option<Fraction<T>> {
+        let width = mem::size_of::<T>();
+        if bytes.len() != 2 * width {
+            return None;
+        }
+
+        let (n_bytes, d_bytes) = bytes.split_at(width);
+        let numerator = T::from_le_bytes(<&T::Bytes>::try_from(n_bytes).ok()?);
+        let denominator = T::from_le_bytes(<&T::Bytes>::try_from(d_bytes).ok()?);
+
+        if denominator.is_zero() {
+            return None;
+        }
+
+        Some(Fraction::from_valid_parts(numerator, denominator))
+    }
+}
+
+impl<T: Integer + Signed + Copy + FromPrimitive> Fraction<T> {
+    /// Returns `n` equally spaced fractions from `start` to `end` inclusive.
+    ///
+    /// The spacing `(end - start) / (n - 1)` is exact,
+    /// so the last element is always equal to `end` (unless `n` is 1,
+    /// in which case only `start` is returned).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n - 1` does not fit in the backing type.
+    pub fn linspace(start: Fraction<T>, end: Fraction<T>, n: usize) -> Vec<Fraction<T>> {
+        if n <= 1 {
+            return vec![start; n];
+        }
+
+        let intervals = T::from_usize(n - 1).expect("Too many points for the backing type");
+        let step = (end - start) / intervals;
+
+        Fraction::step_by(start, step, n).collect()
+    }
+
+    fn from_f64_exact(value: f64) -> Option<Fraction<T>> {
+        if !value.is_finite() {
+            return None;
+        }
+
+        let bits = value.to_bits();
+        let negative = bits >> 63 == 1;
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction_bits = bits & ((1 << 52) - 1);
+
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction_bits, -1074)
+        } else {
+            (fraction_bits | (1 << 52), biased_exponent - 1075)
+        };
+
+        if mantissa == 0 {
+            return Some(Fraction::from_valid_parts(T::zero(), T::one()));
+        }
+
+        let trailing_zeros = mantissa.trailing_zeros();
+        let mantissa = u128::from(mantissa >> trailing_zeros);
+        let exponent = exponent + trailing_zeros as i32;
+
+        let (magnitude, denominator) = if exponent >= 0 {
+            if exponent as u32 + (128 - mantissa.leading_zeros()) > 128 {
+                return None;
+            }
+            (mantissa << exponent, 1)
+        } else {
+            if -exponent >= 128 {
+                return None;
+            }
+            (mantissa, 1 << -exponent)
+        };
+
+        // the sign is applied before narrowing, since `T::MIN` has no positive counterpart
+        let numerator = if negative {
+            0i128.checked_sub_unsigned(magnitude)?
+        } else {
+            i128::try_from(magnitude).ok()?
+        };
+
+        let numerator = T::from_i128(numerator)?;
+        let denominator = T::from_u128(denominator)?;
+
+        Some(Fraction::<T> {
+            numerator,
+            denominator,
+        })
+    }
+}
+
+impl<T: Integer + Copy> Fraction<T> {
+    fn from_valid_parts(numerator: T, denominator: T) -> Fraction<T> {
+        // reducing also moves the sign of a negative denominator into the numerator,
+        // which is a no-op for unsigned backings
+        let (numerator, denominator) = reduce(numerator, denominator);
 
         Fraction::<T> {
             numerator,
@@ -50,46 +1984,55 @@ impl<T: Integer + Signed + Copy> Fraction<T> {
         }
     }
 
-    pub fn numerator(&self) -> T {
-        self.numerator
-    }
+    /// Finds the simplest fraction in the open interval `(a/b, c/d)`, with `0 <= a/b`,
+    /// where a missing upper bound stands for infinity.
+    ///
+    /// Works on the continued fraction expansions of both bounds,
+    /// so no intermediate value exceeds the bounds or the result.
+    fn simplest_between_impl((a, b): (T, T), high: Option<(T, T)>) -> (T, T) {
+        let (whole, rest) = a.div_mod_floor(&b);
+        let next = whole + T::one();
 
-    pub fn denominator(&self) -> T {
-        self.denominator
-    }
+        let (c, d) = match high {
+            Some((c, d)) if next > (c - T::one()).div_floor(&d) => (c, d),
+            _ => return (next, T::one()),
+        };
 
-    /// Returns a tuple in the form `(numerator, denominator)`.
-    pub fn get_as_tuple(&self) -> (T, T) {
-        (self.numerator, self.denominator)
-    }
+        // both bounds lie in [whole, whole + 1], so recurse on the reciprocals of their fractional parts
+        let (high_whole, high_rest) = c.div_mod_floor(&d);
+        let high_rest = if high_whole == whole { high_rest } else { d };
 
-    /// Returns `true` if the fraction is proper,
-    /// i.e. the absolute value of the numerator
-    /// is lower than the denominator.
-    pub fn is_proper(&self) -> bool {
-        abs(self.numerator) < self.denominator
+        let upper = if rest.is_zero() {
+            None
+        } else {
+            Some((b, rest))
+        };
+        let (numerator, denominator) = Fraction::simplest_between_impl((d, high_rest), upper);
+
+        (whole * numerator + denominator, numerator)
     }
 
-    /// Returns a new fraction that is the inverse of this fraction, i.e. 1/f.
+    /// Performs a single step of decimal long division,
+    /// replacing `remainder` with `10 * remainder mod denominator`.
     ///
-    /// # Panics
-    ///
-    /// Panics if the original fraction is a zero.
-    pub fn reciprocal(&self) -> Fraction<T> {
-        if self.numerator.is_zero() {
-            panic!("Cannot reverse a zero");
+    /// The multiplication is done by repeated modular addition,
+    /// so that large denominators do not overflow.
+    fn next_decimal_digit(remainder: &mut T, denominator: T) -> u8 {
+        let mut digit = 0;
+        let mut acc = T::zero();
+        for _ in 0..10 {
+            if acc >= denominator - *remainder {
+                acc = acc - (denominator - *remainder);
+                digit += 1;
+            } else {
+                acc = acc + *remainder;
+            }
         }
 
-        let (numerator, denominator) = normalize_sign(self.denominator, self.numerator);
-
-        Fraction::<T> {
-            numerator,
-            denominator,
-        }
+        *remainder = acc;
+        digit
     }
-}
 
-impl<T: Integer + Signed + Copy> Fraction<T> {
     fn add_impl(&self, other: &Self) -> (T, T) {
         let denom = lcm(self.denominator, other.denominator);
         let num = (self.numerator * denom) / self.denominator
@@ -98,6 +2041,8 @@ impl<T: Integer + Signed + Copy> Fraction<T> {
         reduce(num, denom)
     }
 
+    // the scaled numerators are subtracted directly instead of negating `other`,
+    // so a `T::MIN` operand only overflows if the difference itself does not fit
     fn sub_impl(&self, other: &Self) -> (T, T) {
         let denom = lcm(self.denominator, other.denominator);
         let num = (self.numerator * denom) / self.denominator
@@ -107,9 +2052,17 @@ impl<T: Integer + Signed + Copy> Fraction<T> {
     }
 
     fn mul_impl(&self, other: &Self) -> (T, T) {
-        reduce(
-            self.numerator * other.numerator,
-            self.denominator * other.denominator,
+        if self.numerator.is_zero() || other.numerator.is_zero() {
+            return (T::zero(), T::one());
+        }
+
+        // cross-reducing keeps the products as small as the result allows
+        let gcd_ad = gcd(self.numerator, other.denominator);
+        let gcd_cb = gcd(other.numerator, self.denominator);
+
+        (
+            (self.numerator / gcd_ad) * (other.numerator / gcd_cb),
+            (self.denominator / gcd_cb) * (other.denominator / gcd_ad),
         )
     }
 
@@ -121,9 +2074,138 @@ impl<T: Integer + Signed + Copy> Fraction<T> {
     }
 }
 
-impl<T: fmt::Display> fmt::Display for Fraction<T> {
+impl<T: Integer + Signed + Copy> Fraction<T> {
+    fn mul_int_impl(&self, scalar: T) -> (T, T) {
+        let gcd = gcd(scalar, self.denominator);
+
+        (self.numerator * (scalar / gcd), self.denominator / gcd)
+    }
+
+    fn div_int_impl(&self, scalar: T) -> (T, T) {
+        if self.numerator.is_zero() {
+            return (T::zero(), T::one());
+        }
+
+        let gcd = gcd(self.numerator, scalar);
+
+        normalize_sign(self.numerator / gcd, self.denominator * (scalar / gcd))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Fraction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fraction({:?}/{:?})", self.numerator, self.denominator)
+    }
+}
+
+impl<T: fmt::Display> Fraction<T> {
+    /// Renders the fraction as a ratio with a custom separator,
+    /// e.g. `3/4` with `':'` becomes `"3:4"`.
+    pub fn to_string_with_separator(&self, sep: char) -> String {
+        format!("{}{}{}", self.numerator, sep, self.denominator)
+    }
+}
+
+/// The alternate flag (`{:#}`) displays the fraction as a mixed number,
+/// e.g. `7/3` is displayed as `2 1/3`.
+///
+/// If a precision is given, the fraction is displayed as a decimal
+/// rounded to that many places instead, e.g. `{:.3}` displays `1/3` as `0.333`.
+impl<T: fmt::Display + Integer + Copy> fmt::Display for Fraction<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}/{}", self.numerator, self.denominator)
+        if let Some(precision) = f.precision() {
+            return f.write_str(&self.format_decimal(precision));
+        }
+
+        if !f.alternate() {
+            return write!(f, "{}/{}", self.numerator, self.denominator);
+        }
+
+        f.write_str(&self.to_mixed_string(" "))
+    }
+}
+
+impl<T: fmt::Display + Integer + Copy> Fraction<T> {
+    /// Renders the fraction as a mixed number,
+    /// with `whole_sep` between the whole and the fractional part,
+    /// e.g. `7/3` with `"-"` becomes `"2-1/3"`.
+    ///
+    /// Whole numbers and proper fractions are rendered without a separator,
+    /// e.g. `"4"` or `"-1/3"`.
+    pub fn to_mixed_string(&self, whole_sep: &str) -> String {
+        let (whole, rest) = self.to_mixed();
+        if rest.numerator.is_zero() {
+            whole.to_string()
+        } else if whole.is_zero() {
+            format!("{}/{}", rest.numerator, rest.denominator)
+        } else {
+            // the remainder is smaller than the denominator, so its magnitude always fits
+            let rest_magnitude = if rest.numerator < T::zero() {
+                T::zero() - rest.numerator
+            } else {
+                rest.numerator
+            };
+
+            format!(
+                "{}{}{}/{}",
+                whole, whole_sep, rest_magnitude, rest.denominator
+            )
+        }
+    }
+
+    fn format_decimal(&self, precision: usize) -> String {
+        let (whole, rest) = self.to_mixed();
+        let negative = self.numerator < T::zero();
+
+        let mut digits: Vec<u8> = whole
+            .to_string()
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(|digit| digit - b'0')
+            .collect();
+
+        let mut remainder = if rest.numerator < T::zero() {
+            T::zero() - rest.numerator
+        } else {
+            rest.numerator
+        };
+        for _ in 0..precision {
+            digits.push(Fraction::next_decimal_digit(
+                &mut remainder,
+                rest.denominator,
+            ));
+        }
+
+        // round half away from zero, carrying into the preceding digits
+        if Fraction::next_decimal_digit(&mut remainder, rest.denominator) >= 5 {
+            let mut carry = true;
+            for digit in digits.iter_mut().rev() {
+                if *digit == 9 {
+                    *digit = 0;
+                } else {
+                    *digit += 1;
+                    carry = false;
+                    break;
+                }
+            }
+
+            if carry {
+                digits.insert(0, 1);
+            }
+        }
+
+        let (integral, fractional) = digits.split_at(digits.len() - precision);
+        let mut result = String::new();
+        if negative {
+            result.push('-');
+        }
+        result.extend(integral.iter().map(|&digit| char::from(b'0' + digit)));
+        if precision > 0 {
+            result.push('.');
+            result.extend(fractional.iter().map(|&digit| char::from(b'0' + digit)));
+        }
+
+        result
     }
 }
 
@@ -145,22 +2227,137 @@ where
     }
 }
 
+/// Integer conversions truncate toward zero,
+/// while conversions to unsigned types fail for negative fractions.
+impl<T: Integer + Signed + Copy + ToPrimitive> ToPrimitive for Fraction<T> {
+    fn to_i64(&self) -> Option<i64> {
+        (self.numerator / self.denominator).to_i64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        (self.numerator / self.denominator).to_i128()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        if self.numerator.is_negative() {
+            return None;
+        }
+
+        (self.numerator / self.denominator).to_u64()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        if self.numerator.is_negative() {
+            return None;
+        }
+
+        (self.numerator / self.denominator).to_u128()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.numerator.to_f64()? / self.denominator.to_f64()?)
+    }
+}
+
+/// Integer conversions are exact and produce fractions with a denominator of 1.
+///
+/// Float conversions are exact as well: every finite float is a dyadic rational,
+/// so e.g. `0.5` becomes `1/2` but `0.1` becomes `3602879701896397/36028797018963968`.
+/// `None` is returned for non-finite values and values whose exact form
+/// does not fit in the backing type.
+impl<T: Integer + Signed + Copy + FromPrimitive> FromPrimitive for Fraction<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Fraction::from_valid_parts(T::from_i64(n)?, T::one()))
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(Fraction::from_valid_parts(T::from_i128(n)?, T::one()))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Fraction::from_valid_parts(T::from_u64(n)?, T::one()))
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(Fraction::from_valid_parts(T::from_u128(n)?, T::one()))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Fraction::from_f64_exact(n)
+    }
+}
+
+macro_rules! impl_from_widening {
+    ( $from:ty => $( $to:ty ),+ ) => {
+        $(
+            impl From<Fraction<$from>> for Fraction<$to> {
+                fn from(f: Fraction<$from>) -> Self {
+                    Fraction::<$to> {
+                        numerator: f.numerator.into(),
+                        denominator: f.denominator.into(),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_from_widening!(i8 => i16, i32, i64);
+impl_from_widening!(i16 => i32, i64);
+impl_from_widening!(i32 => i64);
+
+macro_rules! impl_add_widening {
+    ( $narrow:ty => $( $wide:ty ),+ ) => {
+        $(
+            impl Add<Fraction<$narrow>> for Fraction<$wide> {
+                type Output = Fraction<$wide>;
+
+                fn add(self, other: Fraction<$narrow>) -> Fraction<$wide> {
+                    self + Fraction::<$wide>::from(other)
+                }
+            }
+
+            impl Add<Fraction<$wide>> for Fraction<$narrow> {
+                type Output = Fraction<$wide>;
+
+                fn add(self, other: Fraction<$wide>) -> Fraction<$wide> {
+                    Fraction::<$wide>::from(self) + other
+                }
+            }
+        )+
+    };
+}
+
+impl_add_widening!(i8 => i16, i32, i64);
+impl_add_widening!(i16 => i32, i64);
+impl_add_widening!(i32 => i64);
+
 impl<T: FromStr + Integer> FromStr for Fraction<T> {
     type Err = FractionParseError<T::Err>;
 
+    /// Parses a fraction in the form `<N>/<D>`, or a bare integer `<N>` as `<N>/1`.
+    ///
+    /// Input without a separator that is not an optionally signed run of digits
+    /// is reported as incorrectly formed, while a run of digits that fails to parse,
+    /// e.g. one out of range, reports the error for the numerator.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (n_unparsed, d_unparsed) = s
-            .splitn(2, '/')
-            .collect_tuple()
-            .ok_or(FractionParseError::IncorrectForm)?;
+        if !s.contains('/') {
+            let digits = s.strip_prefix(|c| c == '+' || c == '-').unwrap_or(s);
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(FractionParseError::IncorrectForm);
+            }
 
-        let numerator = T::from_str(n_unparsed).map_err(FractionParseError::NumParseError)?;
-        let denominator = T::from_str(d_unparsed).map_err(FractionParseError::NumParseError)?;
+            let numerator = T::from_str(s)
+                .map_err(|err| FractionParseError::in_component(Component::Numerator, s, err))?;
 
-        if denominator.is_zero() {
-            return Err(FractionParseError::ZeroDenominator);
+            return Ok(Fraction::<T> {
+                numerator,
+                denominator: T::one(),
+            });
         }
 
+        let (numerator, denominator) = parse_parts(s, '/')?;
+
         Ok(Fraction::<T> {
             numerator,
             denominator,
@@ -168,16 +2365,251 @@ impl<T: FromStr + Integer> FromStr for Fraction<T> {
     }
 }
 
-impl<T: PartialOrd + Integer + Copy> PartialOrd for Fraction<T> {
+/// Parses both parts of a fraction separated by `sep`,
+/// rejecting a zero denominator.
+fn parse_parts<T: FromStr + Integer>(
+    s: &str,
+    sep: char,
+) -> Result<(T, T), FractionParseError<T::Err>> {
+    let (n_unparsed, d_unparsed) = split_parts(s, sep)?;
+
+    let numerator = T::from_str(n_unparsed)
+        .map_err(|err| FractionParseError::in_component(Component::Numerator, n_unparsed, err))?;
+    let denominator = T::from_str(d_unparsed)
+        .map_err(|err| FractionParseError::in_component(Component::Denominator, d_unparsed, err))?;
+
+    if denominator.is_zero() {
+        return Err(FractionParseError::ZeroDenominator);
+    }
+
+    Ok((numerator, denominator))
+}
+
+/// The last two convergents `p0/q0` and `p1/q1` of a continued fraction expansion,
+/// walked as far as a bound on their denominators allows.
+struct Convergents<T, A> {
+    p0: T,
+    q0: T,
+    p1: T,
+    q1: T,
+    /// The first term left out by the bound, or `None` if the expansion ended first.
+    cut: Option<A>,
+}
+
+impl<T: Integer + Copy, A> Convergents<T, A> {
+    /// Returns the largest `k` for which the semiconvergent `(p0 + k*p1)/(q0 + k*q1)`
+    /// stays within `max_den`, along with its numerator computed by `numerator`,
+    /// or `None` for the numerator if it does not fit.
+    fn semiconvergent(
+        &self,
+        max_den: T,
+        numerator: fn(T, T, T) -> Option<T>,
+    ) -> (T, Option<Fraction<T>>) {
+        let k = (max_den - self.q0) / self.q1;
+        let semiconvergent = if k.is_zero() {
+            Some(self.p0)
+        } else {
+            numerator(k, self.p1, self.p0)
+        };
+
+        let semiconvergent = semiconvergent.map(|numerator| Fraction::<T> {
+            numerator,
+            denominator: self.q0 + k * self.q1,
+        });
+
+        (k, semiconvergent)
+    }
+}
+
+/// Walks the convergents of the continued fraction with the given terms
+/// for as long as their denominators do not exceed `max_den`.
+///
+/// `to_term` converts a term, returning `None` for terms too large for `T`, which are cut off,
+/// and `numerator(a, p1, p0)` computes the next numerator `p0 + a*p1`.
+/// Returns `None` if the first term or one of the numerators does not fit.
+fn walk_convergents<T, A, I, F>(
+    terms: I,
+    max_den: T,
+    to_term: F,
+    numerator: fn(T, T, T) -> Option<T>,
+) -> Option<Convergents<T, A>>
+where
+    T: Integer + Copy,
+    A: Copy,
+    I: IntoIterator<Item = A>,
+    F: Fn(A) -> Option<T>,
+{
+    let mut terms = terms.into_iter();
+    let a = to_term(terms.next()?)?;
+    let (mut p0, mut q0, mut p1, mut q1) = (T::one(), T::zero(), a, T::one());
+
+    for term in terms {
+        let a = match to_term(term) {
+            Some(a) if a <= (max_den - q0) / q1 => a,
+            _ => {
+                return Some(Convergents {
+                    p0,
+                    q0,
+                    p1,
+                    q1,
+                    cut: Some(term),
+                })
+            }
+        };
+
+        let p2 = numerator(a, p1, p0)?;
+        let q2 = q0 + a * q1;
+        p0 = p1;
+        q0 = q1;
+        p1 = p2;
+        q1 = q2;
+    }
+
+    Some(Convergents {
+        p0,
+        q0,
+        p1,
+        q1,
+        cut: None,
+    })
+}
+
+/// Computes the convergent numerator `p0 + a*p1` for a positive term `a`.
+///
+/// After the first term `p0 = 1` may differ in sign from `p1`, so `p0 + p1` is added first,
+/// keeping every step within the final value.
+fn convergent_numerator<T: Integer + Copy>(a: T, p1: T, p0: T) -> Option<T> {
+    Some(p0 + p1 + (a - T::one()) * p1)
+}
+
+/// Computes the convergent numerator `p0 + a*p1` like `convergent_numerator`,
+/// returning `None` if it does not fit.
+fn checked_convergent_numerator<T>(a: T, p1: T, p0: T) -> Option<T>
+where
+    T: Integer + Copy + CheckedAdd + CheckedMul,
+{
+    p0.checked_add(&p1)?
+        .checked_add(&(a - T::one()).checked_mul(&p1)?)
+}
+
+/// Returns the complete quotients `n/d` of the continued fraction expansion of a fraction,
+/// as `(n, d)` pairs whose whole parts are its terms.
+fn complete_quotients<T: Integer + Copy>(
+    numerator: T,
+    denominator: T,
+) -> impl Iterator<Item = (T, T)> {
+    iter::successors(Some((numerator, denominator)), |&(n, d)| {
+        let rest = n.mod_floor(&d);
+        if rest.is_zero() {
+            None
+        } else {
+            Some((d, rest))
+        }
+    })
+}
+
+/// Checks whether the number with the given continued fraction terms
+/// is greater than `numerator/denominator`, comparing the expansions term by term.
+///
+/// Returns `None` if the terms run out before the comparison is decided.
+fn expansion_exceeds<T, I>(terms: &mut I, numerator: T, denominator: T) -> Option<bool>
+where
+    T: Integer + Copy + ToPrimitive,
+    I: Iterator<Item = u32>,
+{
+    let (mut n, mut d) = (numerator, denominator);
+    // a larger term means a larger value at even depths, and a smaller one at odd depths
+    let mut even_depth = true;
+
+    loop {
+        let a = u64::from(terms.next()?);
+        let (b, rest) = n.div_rem(&d);
+        let b = b.to_u64()?;
+
+        if a != b {
+            return Some((a > b) == even_depth);
+        }
+
+        // the rational expansion ends here, while the irrational one continues
+        if rest.is_zero() {
+            return Some(even_depth);
+        }
+
+        n = d;
+        d = rest;
+        even_depth = !even_depth;
+    }
+}
+
+/// Continued fraction terms of π, enough to find the best approximations
+/// for every denominator bound that fits in 64 bits.
+const PI_TERMS: [u32; 100] = [
+    3, 7, 15, 1, 292, 1, 1, 1, 2, 1, 3, 1, 14, 2, 1, 1, 2, 2, 2, 2, 1, 84, 2, 1, 1, 15, 3, 13, 1,
+    4, 2, 6, 6, 99, 1, 2, 2, 6, 3, 5, 1, 1, 6, 8, 1, 7, 1, 2, 3, 7, 1, 2, 1, 1, 12, 1, 1, 1, 3, 1,
+    1, 8, 1, 1, 2, 1, 6, 1, 1, 5, 2, 2, 3, 1, 2, 4, 4, 16, 1, 161, 45, 1, 22, 1, 2, 2, 1, 4, 1, 2,
+    24, 1, 2, 1, 3, 1, 2, 1, 1, 10,
+];
+
+/// Splits an unparsed fraction into its numerator and denominator,
+/// rejecting input where either of them is missing
+/// or where there is more than one separator.
+fn split_parts<E>(s: &str, sep: char) -> Result<(&str, &str), FractionParseError<E>> {
+    let (n_unparsed, d_unparsed) = s
+        .split(sep)
+        .collect_tuple()
+        .ok_or(FractionParseError::IncorrectForm)?;
+
+    if n_unparsed.is_empty() || d_unparsed.is_empty() {
+        return Err(FractionParseError::IncorrectForm);
+    }
+
+    Ok((n_unparsed, d_unparsed))
+}
+
+impl<T: Integer + Copy> PartialOrd for Fraction<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let a = self.numerator * other.denominator;
-        let b = other.numerator * self.denominator;
+        Some(self.cmp(other))
+    }
+}
 
-        a.partial_cmp(&b)
+impl<T: Integer + Copy> Ord for Fraction<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // compare the continued fraction expansions term by term
+        // instead of cross-multiplying, which could overflow
+        let (mut a, mut b) = (self.numerator, self.denominator);
+        let (mut c, mut d) = (other.numerator, other.denominator);
+        let mut reversed = false;
+
+        loop {
+            let (q1, r1) = a.div_mod_floor(&b);
+            let (q2, r2) = c.div_mod_floor(&d);
+
+            let ordering = match (r1.is_zero(), r2.is_zero()) {
+                _ if q1 != q2 => q1.cmp(&q2),
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => {
+                    // r1/b and r2/d are compared through their reciprocals b/r1 and d/r2
+                    a = b;
+                    b = r1;
+                    c = d;
+                    d = r2;
+                    reversed = !reversed;
+                    continue;
+                }
+            };
+
+            return if reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+        }
     }
 }
 
-impl<T: Integer + Signed + Copy> Add for Fraction<T> {
+impl<T: Integer + Copy> Add for Fraction<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
@@ -190,7 +2622,7 @@ impl<T: Integer + Signed + Copy> Add for Fraction<T> {
     }
 }
 
-impl<T: Integer + Signed + Copy> AddAssign for Fraction<T> {
+impl<T: Integer + Copy> AddAssign for Fraction<T> {
     fn add_assign(&mut self, other: Self) {
         let (numerator, denominator) = self.add_impl(&other);
 
@@ -201,9 +2633,14 @@ impl<T: Integer + Signed + Copy> AddAssign for Fraction<T> {
     }
 }
 
-impl<T: Integer + Signed + Copy> Sub for Fraction<T> {
+impl<T: Integer + Copy> Sub for Fraction<T> {
     type Output = Self;
 
+    /// # Panics
+    ///
+    /// Overflows (panicking in debug builds) if the difference,
+    /// or a numerator scaled to the common denominator, does not fit in `T`.
+    /// Use `checked_sub` to detect this instead.
     fn sub(self, other: Self) -> Self {
         let (numerator, denominator) = self.sub_impl(&other);
 
@@ -214,7 +2651,7 @@ impl<T: Integer + Signed + Copy> Sub for Fraction<T> {
     }
 }
 
-impl<T: Integer + Signed + Copy> SubAssign for Fraction<T> {
+impl<T: Integer + Copy> SubAssign for Fraction<T> {
     fn sub_assign(&mut self, other: Self) {
         let (numerator, denominator) = self.sub_impl(&other);
 
@@ -225,6 +2662,9 @@ impl<T: Integer + Signed + Copy> SubAssign for Fraction<T> {
     }
 }
 
+/// Negating a fraction whose numerator is `T::MIN` overflows
+/// (panicking in debug builds and wrapping back to `T::MIN` otherwise).
+/// Use `checked_neg` for untrusted inputs.
 impl<T: Integer + Signed + Copy> Neg for Fraction<T> {
     type Output = Self;
 
@@ -236,7 +2676,7 @@ impl<T: Integer + Signed + Copy> Neg for Fraction<T> {
     }
 }
 
-impl<T: Integer + Signed + Copy> Mul for Fraction<T> {
+impl<T: Integer + Copy> Mul for Fraction<T> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self {
@@ -249,7 +2689,7 @@ impl<T: Integer + Signed + Copy> Mul for Fraction<T> {
     }
 }
 
-impl<T: Integer + Signed + Copy> MulAssign for Fraction<T> {
+impl<T: Integer + Copy> MulAssign for Fraction<T> {
     fn mul_assign(&mut self, rhs: Self) {
         let (numerator, denominator) = self.mul_impl(&rhs);
 
@@ -260,7 +2700,63 @@ impl<T: Integer + Signed + Copy> MulAssign for Fraction<T> {
     }
 }
 
-impl<T: Integer + Signed + Copy> Div for Fraction<T> {
+/// Sums with checked arithmetic, so an overflow panics even in release builds
+/// instead of silently wrapping around.
+///
+/// The whole and fractional parts are added separately,
+/// so this only panics if a partial sum or the least common multiple
+/// of the denominators does not fit, e.g. `-127 + 127/2` on `Fraction8` is fine.
+///
+/// Use `Fraction::checked_sum` to detect overflow instead of panicking.
+impl<T: Integer + Copy + CheckedAdd + CheckedMul> Sum for Fraction<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Fraction::from_valid_parts(T::zero(), T::one()), |acc, f| {
+            let (numerator, denominator) = acc
+                .checked_add_mixed(&f)
+                .expect("Overflow when summing fractions");
+
+            Fraction::<T> {
+                numerator,
+                denominator,
+            }
+        })
+    }
+}
+
+impl<'a, T: Integer + Copy + CheckedAdd + CheckedMul + 'a> Sum<&'a Fraction<T>> for Fraction<T> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Multiplies with checked arithmetic, so an overflow panics even in release builds
+/// instead of silently wrapping around.
+///
+/// The operands are cross-reduced first, so this only panics if a partial product does not fit.
+impl<T: Integer + Copy + CheckedAdd + CheckedMul> Product for Fraction<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Fraction::from_valid_parts(T::one(), T::one()), |acc, f| {
+            let (numerator, denominator) = acc
+                .checked_mul_impl(&f)
+                .expect("Overflow when multiplying fractions");
+
+            Fraction::<T> {
+                numerator,
+                denominator,
+            }
+        })
+    }
+}
+
+impl<'a, T: Integer + Copy + CheckedAdd + CheckedMul + 'a> Product<&'a Fraction<T>>
+    for Fraction<T>
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<T: Integer + Copy> Div for Fraction<T> {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self {
@@ -277,7 +2773,7 @@ impl<T: Integer + Signed + Copy> Div for Fraction<T> {
     }
 }
 
-impl<T: Integer + Signed + Copy> DivAssign for Fraction<T> {
+impl<T: Integer + Copy> DivAssign for Fraction<T> {
     fn div_assign(&mut self, rhs: Self) {
         if rhs.numerator.is_zero() {
             panic!("Cannot divide by zero");
@@ -291,3 +2787,63 @@ impl<T: Integer + Signed + Copy> DivAssign for Fraction<T> {
         }
     }
 }
+
+impl<T: Integer + Signed + Copy> Mul<T> for Fraction<T> {
+    type Output = Self;
+
+    /// Multiplies the fraction by an integer,
+    /// reducing the integer against the denominator first to avoid overflow.
+    fn mul(self, rhs: T) -> Self {
+        let (numerator, denominator) = self.mul_int_impl(rhs);
+
+        Fraction::<T> {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+impl<T: Integer + Signed + Copy> MulAssign<T> for Fraction<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        let (numerator, denominator) = self.mul_int_impl(rhs);
+
+        *self = Self {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+impl<T: Integer + Signed + Copy> Div<T> for Fraction<T> {
+    type Output = Self;
+
+    /// Divides the fraction by an integer,
+    /// reducing the integer against the numerator first to avoid overflow.
+    fn div(self, rhs: T) -> Self {
+        if rhs.is_zero() {
+            panic!("Cannot divide by zero");
+        }
+
+        let (numerator, denominator) = self.div_int_impl(rhs);
+
+        Fraction::<T> {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+impl<T: Integer + Signed + Copy> DivAssign<T> for Fraction<T> {
+    fn div_assign(&mut self, rhs: T) {
+        if rhs.is_zero() {
+            panic!("Cannot divide by zero");
+        }
+
+        let (numerator, denominator) = self.div_int_impl(rhs);
+
+        *self = Self {
+            numerator,
+            denominator,
+        }
+    }
+}
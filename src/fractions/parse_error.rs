@@ -1,22 +1,54 @@
 use std::error::Error;
 use std::fmt;
 
+/// Identifies which part of a fraction failed to parse.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Component {
+    Numerator,
+    Denominator,
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Component::Numerator => write!(f, "numerator"),
+            Component::Denominator => write!(f, "denominator"),
+        }
+    }
+}
+
 /// Defines types of errors that might occur when parsing fractions.
 #[derive(Debug, PartialEq)]
 pub enum FractionParseError<E> {
     IncorrectForm,
     ZeroDenominator,
-    NumParseError(E),
+    /// The parsed value is valid, but does not fit in the backing type.
+    Overflow,
+    /// Parsing one of the components failed;
+    /// `text` holds the offending input and `source` the underlying error.
+    NumParseError {
+        part: Component,
+        text: String,
+        source: E,
+    },
 }
 
 impl<E> FractionParseError<E> {
+    pub(crate) fn in_component(part: Component, text: &str, source: E) -> Self {
+        FractionParseError::NumParseError {
+            part,
+            text: text.to_string(),
+            source,
+        }
+    }
+
     /// Converts from `FractionParseError<E>` to `Option<E>`.
     ///
     /// Converts `self` into an `Option<E>`, consuming `self`,
     /// and discarding errors other than `NumParseError`
     pub fn num_parse_error(self) -> Option<E> {
         match self {
-            FractionParseError::NumParseError(err) => Some(err),
+            FractionParseError::NumParseError { source, .. } => Some(source),
             _ => None,
         }
     }
@@ -27,14 +59,30 @@ impl<E> FractionParseError<E> {
     /// leaving the original in place.
     pub fn as_num_parse_error(&self) -> Option<&E> {
         match self {
-            FractionParseError::NumParseError(err) => Some(err),
+            FractionParseError::NumParseError { source, .. } => Some(source),
             _ => None,
         }
     }
 
     /// Returns `true` if an underlying parse error occurred.
     pub fn is_num_parse_error(&self) -> bool {
-        matches!(self, FractionParseError::NumParseError(_))
+        matches!(self, FractionParseError::NumParseError { .. })
+    }
+
+    /// Returns the component that failed to parse, if any.
+    pub fn component(&self) -> Option<Component> {
+        match self {
+            FractionParseError::NumParseError { part, .. } => Some(*part),
+            _ => None,
+        }
+    }
+
+    /// Returns the input text that failed to parse, if any.
+    pub fn offending_text(&self) -> Option<&str> {
+        match self {
+            FractionParseError::NumParseError { text, .. } => Some(text),
+            _ => None,
+        }
     }
 
     /// Returns `true` if an incorrect form error occurred.
@@ -42,6 +90,11 @@ impl<E> FractionParseError<E> {
         matches!(self, FractionParseError::IncorrectForm)
     }
 
+    /// Returns `true` if an overflow error occurred.
+    pub fn is_overflow(&self) -> bool {
+        matches!(self, FractionParseError::Overflow)
+    }
+
     /// Returns `true` if a zero denominator error occurred.
     pub fn is_zero_denominator(&self) -> bool {
         matches!(self, FractionParseError::ZeroDenominator)
@@ -52,11 +105,19 @@ impl<E: fmt::Display> fmt::Display for FractionParseError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             FractionParseError::IncorrectForm => {
-                write!(f, "Incorrectly formed fraction (format should be <D>/<N>)")
+                write!(
+                    f,
+                    "Incorrectly formed fraction (format should be <N>/<D>, with a single separator)"
+                )
             }
             FractionParseError::ZeroDenominator => write!(f, "Fraction denominator cannot be zero"),
-            FractionParseError::NumParseError(err) => {
-                write!(f, "Error when parsing fraction: {}", err)
+            FractionParseError::Overflow => write!(f, "Fraction does not fit in the backing type"),
+            FractionParseError::NumParseError { part, text, source } => {
+                write!(
+                    f,
+                    "Error when parsing fraction {} `{}`: {}",
+                    part, text, source
+                )
             }
         }
     }
@@ -65,7 +126,7 @@ impl<E: fmt::Display> fmt::Display for FractionParseError<E> {
 impl<E: Error + 'static> Error for FractionParseError<E> {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            FractionParseError::NumParseError(err) => Some(err),
+            FractionParseError::NumParseError { source, .. } => Some(source),
             _ => None,
         }
     }
@@ -0,0 +1,58 @@
+use num::{Integer, Num, Signed};
+
+use super::parse_error::{Component, FractionParseError};
+use super::{split_parts, Fraction};
+
+/// Parser for fractions written in an arbitrary radix,
+/// e.g. `"ff/10"` in radix 16.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FractionParser {
+    radix: u32,
+}
+
+impl FractionParser {
+    /// Create a new parser for the given radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    pub fn new(radix: u32) -> FractionParser {
+        if !(2..=36).contains(&radix) {
+            panic!("Radix must be in the range [2, 36]");
+        }
+
+        FractionParser { radix }
+    }
+
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// Parses a fraction in the form `<N>/<D>`,
+    /// with both parts written in this parser's radix.
+    pub fn parse<T>(&self, s: &str) -> Result<Fraction<T>, FractionParseError<T::FromStrRadixErr>>
+    where
+        T: Num + Integer + Signed + Copy,
+    {
+        let (n_unparsed, d_unparsed) = split_parts(s, '/')?;
+
+        let numerator = T::from_str_radix(n_unparsed, self.radix).map_err(|err| {
+            FractionParseError::in_component(Component::Numerator, n_unparsed, err)
+        })?;
+        let denominator = T::from_str_radix(d_unparsed, self.radix).map_err(|err| {
+            FractionParseError::in_component(Component::Denominator, d_unparsed, err)
+        })?;
+
+        if denominator.is_zero() {
+            return Err(FractionParseError::ZeroDenominator);
+        }
+
+        Ok(Fraction::from_valid_parts(numerator, denominator))
+    }
+}
+
+impl Default for FractionParser {
+    fn default() -> Self {
+        FractionParser { radix: 10 }
+    }
+}
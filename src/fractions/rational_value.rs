@@ -0,0 +1,43 @@
+use std::cmp::Ordering;
+
+use num::Integer;
+
+use super::Fraction;
+
+/// Object-safe view of a rational value,
+/// allowing fractions with different backing types
+/// to be stored and compared as `dyn RationalValue`.
+pub trait RationalValue {
+    /// Returns the numerator and (positive) denominator widened to `i128`.
+    fn to_i128_parts(&self) -> (i128, i128);
+
+    /// Approximates the value as an `f64`, dividing the converted parts.
+    fn as_f64(&self) -> f64 {
+        let (numerator, denominator) = self.to_i128_parts();
+        numerator as f64 / denominator as f64
+    }
+
+    /// Compares two rational values exactly, regardless of their backing types.
+    fn cmp_rational(&self, other: &dyn RationalValue) -> Ordering {
+        let (a, b) = self.to_i128_parts();
+        let (c, d) = other.to_i128_parts();
+
+        // `Ord` compares without cross-multiplying, so even 128-bit parts cannot overflow
+        let lhs = Fraction::<i128> {
+            numerator: a,
+            denominator: b,
+        };
+        let rhs = Fraction::<i128> {
+            numerator: c,
+            denominator: d,
+        };
+
+        lhs.cmp(&rhs)
+    }
+}
+
+impl<T: Integer + Copy + Into<i128>> RationalValue for Fraction<T> {
+    fn to_i128_parts(&self) -> (i128, i128) {
+        (self.numerator.into(), self.denominator.into())
+    }
+}
@@ -0,0 +1,43 @@
+use num::integer::gcd;
+use num::Integer;
+
+use super::Fraction;
+
+/// Multiplies fractions by a fixed factor,
+/// e.g. a conversion ratio applied in a hot loop.
+///
+/// The factor is kept in lowest terms, so every product is cross-reduced
+/// against it before multiplying, which yields a reduced result
+/// without a final gcd pass over the (larger) full product.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Scaler<T = i32> {
+    factor: Fraction<T>,
+}
+
+impl<T: Integer + Copy> Scaler<T> {
+    pub fn new(factor: Fraction<T>) -> Scaler<T> {
+        Scaler { factor }
+    }
+
+    pub fn factor(&self) -> Fraction<T> {
+        self.factor
+    }
+
+    /// Returns `x` multiplied by the factor.
+    pub fn apply(&self, x: Fraction<T>) -> Fraction<T> {
+        if x.numerator.is_zero() || self.factor.numerator.is_zero() {
+            return Fraction {
+                numerator: T::zero(),
+                denominator: T::one(),
+            };
+        }
+
+        let gcd_xn_fd = gcd(x.numerator, self.factor.denominator);
+        let gcd_fn_xd = gcd(self.factor.numerator, x.denominator);
+
+        Fraction {
+            numerator: (x.numerator / gcd_xn_fd) * (self.factor.numerator / gcd_fn_xd),
+            denominator: (x.denominator / gcd_fn_xd) * (self.factor.denominator / gcd_xn_fd),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+/// Sign of a fraction, kept separately from its magnitude.
+///
+/// Zero is considered `Positive`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Sign {
+    Negative,
+    Positive,
+}
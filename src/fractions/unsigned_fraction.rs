@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::num::TryFromIntError;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num::{Integer, Unsigned};
+
+use super::Fraction;
+
+/// Structure representing a common fraction
+/// whose magnitude is stored in an unsigned integer type,
+/// with the sign kept separately.
+///
+/// Compared to `Fraction<T>` for a signed `T` of the same width,
+/// this doubles the range of representable numerators and denominators.
+///
+/// Zero is always stored as non-negative.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct UnsignedFraction<T = u32> {
+    negative: bool,
+    magnitude: Fraction<T>,
+}
+
+impl<T: Integer + Unsigned + Copy> UnsignedFraction<T> {
+    /// Create a new fraction from its sign and the magnitudes
+    /// of its numerator and denominator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero.
+    pub fn new(negative: bool, numerator: T, denominator: T) -> UnsignedFraction<T> {
+        UnsignedFraction::from_magnitude(negative, Fraction::new(numerator, denominator))
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the absolute value of the fraction.
+    pub fn magnitude(&self) -> Fraction<T> {
+        self.magnitude
+    }
+
+    fn from_magnitude(negative: bool, magnitude: Fraction<T>) -> UnsignedFraction<T> {
+        UnsignedFraction {
+            negative: negative && !magnitude.numerator().is_zero(),
+            magnitude,
+        }
+    }
+}
+
+impl<T: Integer + Unsigned + Copy> From<Fraction<T>> for UnsignedFraction<T> {
+    fn from(magnitude: Fraction<T>) -> Self {
+        UnsignedFraction::from_magnitude(false, magnitude)
+    }
+}
+
+macro_rules! impl_signed_conversions {
+    ( $( $signed:ty => $unsigned:ty ),+ ) => {
+        $(
+            impl From<Fraction<$signed>> for UnsignedFraction<$unsigned> {
+                fn from(f: Fraction<$signed>) -> Self {
+                    let magnitude = Fraction::new(
+                        f.numerator().unsigned_abs(),
+                        f.denominator().unsigned_abs(),
+                    );
+
+                    UnsignedFraction::from_magnitude(f.numerator() < 0, magnitude)
+                }
+            }
+
+            impl TryFrom<UnsignedFraction<$unsigned>> for Fraction<$signed> {
+                type Error = TryFromIntError;
+
+                fn try_from(f: UnsignedFraction<$unsigned>) -> Result<Self, Self::Error> {
+                    let (n, d) = f.magnitude.get_as_tuple();
+                    let denominator = <$signed>::try_from(d)?;
+
+                    let numerator = if !f.negative {
+                        <$signed>::try_from(n)?
+                    } else if n == <$signed>::MIN.unsigned_abs() {
+                        <$signed>::MIN
+                    } else {
+                        -<$signed>::try_from(n)?
+                    };
+
+                    Ok(Fraction::new(numerator, denominator))
+                }
+            }
+        )+
+    };
+}
+
+impl_signed_conversions!(i8 => u8, i16 => u16, i32 => u32, i64 => u64);
+
+impl<T: fmt::Debug> fmt::Debug for UnsignedFraction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.negative { "-" } else { "" };
+        write!(
+            f,
+            "UnsignedFraction({}{:?}/{:?})",
+            sign, self.magnitude.numerator, self.magnitude.denominator
+        )
+    }
+}
+
+impl<T: fmt::Display + Integer + Unsigned + Copy> fmt::Display for UnsignedFraction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        write!(f, "{}", self.magnitude)
+    }
+}
+
+impl<T: Integer + Unsigned + Copy> PartialOrd for UnsignedFraction<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer + Unsigned + Copy> Ord for UnsignedFraction<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl<T: Integer + Unsigned + Copy> Neg for UnsignedFraction<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        UnsignedFraction::from_magnitude(!self.negative, self.magnitude)
+    }
+}
+
+impl<T: Integer + Unsigned + Copy> Add for UnsignedFraction<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.negative == other.negative {
+            return UnsignedFraction::from_magnitude(
+                self.negative,
+                self.magnitude + other.magnitude,
+            );
+        }
+
+        // subtract the smaller magnitude from the larger one, keeping the larger one's sign
+        if self.magnitude >= other.magnitude {
+            UnsignedFraction::from_magnitude(self.negative, self.magnitude - other.magnitude)
+        } else {
+            UnsignedFraction::from_magnitude(other.negative, other.magnitude - self.magnitude)
+        }
+    }
+}
+
+impl<T: Integer + Unsigned + Copy> Sub for UnsignedFraction<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<T: Integer + Unsigned + Copy> Mul for UnsignedFraction<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        UnsignedFraction::from_magnitude(
+            self.negative != rhs.negative,
+            self.magnitude * rhs.magnitude,
+        )
+    }
+}
+
+impl<T: Integer + Unsigned + Copy> Div for UnsignedFraction<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        UnsignedFraction::from_magnitude(
+            self.negative != rhs.negative,
+            self.magnitude / rhs.magnitude,
+        )
+    }
+}
@@ -0,0 +1,152 @@
+use num::integer::gcd;
+use std::char;
+
+use num::{FromPrimitive, Integer, Signed, ToPrimitive};
+
+/// Moves the sign of a `(numerator, denominator)` pair into the numerator,
+/// so that the denominator is never negative.
+///
+/// ```
+/// use fractions::util::normalize_sign;
+///
+/// assert_eq!((-1, 5), normalize_sign(1, -5));
+/// ```
+pub fn normalize_sign<T: Signed>(n: T, d: T) -> (T, T) {
+    if d.is_negative() {
+        (-n, -d)
+    } else {
+        (n, d)
+    }
+}
+
+/// Moves the sign of a `(numerator, denominator)` pair into the numerator
+/// without reducing it, e.g. to keep a chosen denominator.
+///
+/// # Panics
+///
+/// Panics if the denominator is zero.
+///
+/// ```
+/// use fractions::util::with_normalized_sign;
+///
+/// assert_eq!((-2, 10), with_normalized_sign(2, -10));
+/// ```
+pub fn with_normalized_sign<T: Signed>(num: T, den: T) -> (T, T) {
+    if den.is_zero() {
+        panic!("Fraction cannot have a zero denominator");
+    }
+
+    normalize_sign(num, den)
+}
+
+/// Divides both elements of a `(numerator, denominator)` pair
+/// by their greatest common divisor.
+///
+/// A zero numerator always reduces to `(0, 1)`,
+/// and the sign of a negative denominator is moved into the numerator.
+///
+/// ```
+/// use fractions::util::reduce;
+///
+/// assert_eq!((7, 12), reduce(14, 24));
+/// assert_eq!((-1, 3), reduce(3, -9));
+/// ```
+pub fn reduce<T: Integer + Copy>(a: T, b: T) -> (T, T) {
+    if a.is_zero() {
+        return (T::zero(), T::one());
+    }
+
+    // the gcd is never negative, so the quotients keep the signs of the arguments
+    let gcd = gcd(a, b);
+    let (a, b) = (a / gcd, b / gcd);
+
+    if b < T::zero() {
+        (T::zero() - a, T::zero() - b)
+    } else {
+        (a, b)
+    }
+}
+
+/// Checks whether two `(numerator, denominator)` pairs represent the same value,
+/// by reducing both of them.
+///
+/// The denominators are expected to be non-zero.
+///
+/// ```
+/// use fractions::util::equivalent;
+///
+/// assert!(equivalent((2, 4), (-1, -2)));
+/// assert!(!equivalent((1, 2), (1, 3)));
+/// ```
+pub fn equivalent<T: Integer + Copy>(a: (T, T), b: (T, T)) -> bool {
+    reduce(a.0, a.1) == reduce(b.0, b.1)
+}
+
+/// Returns the factor by which `Fraction::new` scales down a `(numerator, denominator)` pair,
+/// i.e. their (non-negative) greatest common divisor.
+///
+/// ```
+/// use fractions::util::reduction_factor;
+///
+/// assert_eq!(50, reduction_factor(50, 100));
+/// assert_eq!(3, reduction_factor(-3, 9));
+/// ```
+pub fn reduction_factor<T: Integer + Copy>(num: T, den: T) -> T {
+    gcd(num, den)
+}
+
+/// Normalizes the sign of a `(numerator, denominator)` pair
+/// and reduces it in place, exactly as `Fraction::new` would.
+///
+/// The denominator is expected to be non-zero.
+///
+/// ```
+/// use fractions::util::normalize;
+///
+/// let (mut n, mut d) = (-2, -4);
+/// normalize(&mut n, &mut d);
+///
+/// assert_eq!((1, 2), (n, d));
+/// ```
+pub fn normalize<T: Integer + Signed + Copy>(num: &mut T, den: &mut T) {
+    let (n, d) = normalize_sign(*num, *den);
+    let (n, d) = reduce(n, d);
+
+    *num = n;
+    *den = d;
+}
+
+pub(crate) fn int_to_string_radix<T>(n: T, radix: u32) -> String
+where
+    T: Integer + Signed + Copy + FromPrimitive + ToPrimitive,
+{
+    assert!(
+        (2..=36).contains(&radix),
+        "Radix must be in the range [2, 36]"
+    );
+
+    let base = T::from_u32(radix).expect("Radix does not fit in the backing type");
+
+    let mut digits = Vec::new();
+    let mut rest = n;
+    loop {
+        // the remainder keeps the sign of `rest`, so negating it never overflows
+        let digit = abs_digit(rest % base);
+        digits.push(char::from_digit(digit, radix).unwrap());
+
+        rest = rest / base;
+        if rest.is_zero() {
+            break;
+        }
+    }
+
+    if n.is_negative() {
+        digits.push('-');
+    }
+
+    digits.iter().rev().collect()
+}
+
+fn abs_digit<T: ToPrimitive>(digit: T) -> u32 {
+    digit.to_i64().map(i64::unsigned_abs).unwrap() as u32
+}
@@ -0,0 +1,39 @@
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when a fraction would be constructed with a zero denominator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ZeroDenominatorError {
+    index: Option<usize>,
+}
+
+impl ZeroDenominatorError {
+    pub(crate) fn new() -> ZeroDenominatorError {
+        ZeroDenominatorError { index: None }
+    }
+
+    pub(crate) fn at(index: usize) -> ZeroDenominatorError {
+        ZeroDenominatorError { index: Some(index) }
+    }
+
+    /// Returns the position of the offending pair
+    /// if the error occurred while constructing fractions in bulk.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+impl fmt::Display for ZeroDenominatorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.index {
+            Some(index) => write!(
+                f,
+                "Fraction denominator cannot be zero (at index {})",
+                index
+            ),
+            None => write!(f, "Fraction denominator cannot be zero"),
+        }
+    }
+}
+
+impl Error for ZeroDenominatorError {}
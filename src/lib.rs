@@ -1,10 +1,26 @@
 mod fractions;
-pub use crate::fractions::parse_error::FractionParseError;
+pub use crate::fractions::arithmetic_error::ArithmeticError;
+pub use crate::fractions::construction_error::ConstructionError;
+pub use crate::fractions::float_conversion_error::FloatConversionError;
+pub use crate::fractions::fraction_soa::FractionSoa;
+pub use crate::fractions::min_fraction::MinFraction;
+pub use crate::fractions::parse_error::{Component, FractionParseError};
+pub use crate::fractions::parser::FractionParser;
+pub use crate::fractions::rational_value::RationalValue;
+pub use crate::fractions::scaler::Scaler;
+pub use crate::fractions::sign::Sign;
+pub use crate::fractions::unsigned_fraction::UnsignedFraction;
+pub use crate::fractions::util;
+pub use crate::fractions::zero_denominator_error::ZeroDenominatorError;
 pub use crate::fractions::Fraction;
 
 pub type Fraction8 = Fraction<i8>;
 pub type Fraction16 = Fraction<i16>;
+pub type Fraction32 = Fraction<i32>;
 pub type Fraction64 = Fraction<i64>;
+pub type FractionU32 = Fraction<u32>;
+pub type FractionU64 = Fraction<u64>;
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests;
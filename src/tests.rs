@@ -1,6 +1,25 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::convert::TryFrom;
+
+use num::rational::Ratio;
+use num::{FromPrimitive, ToPrimitive};
+
 use crate::frac;
-use crate::fractions::parse_error::FractionParseError;
+use crate::fractions::arithmetic_error::ArithmeticError;
+use crate::fractions::construction_error::ConstructionError;
+use crate::fractions::float_conversion_error::FloatConversionError;
+use crate::fractions::fraction_soa::FractionSoa;
+use crate::fractions::min_fraction::MinFraction;
+use crate::fractions::parse_error::{Component, FractionParseError};
+use crate::fractions::parser::FractionParser;
+use crate::fractions::rational_value::RationalValue;
+use crate::fractions::scaler::Scaler;
+use crate::fractions::sign::Sign;
+use crate::fractions::unsigned_fraction::UnsignedFraction;
+use crate::fractions::util;
 use crate::fractions::Fraction;
+use crate::{Fraction16, Fraction32, Fraction64, Fraction8, FractionU32, FractionU64};
 
 #[test]
 fn fraction_reduces_correctly() {
@@ -16,6 +35,50 @@ fn fraction_should_panic_with_zero_denominator() {
     frac!(1, 0);
 }
 
+#[test]
+fn fractions_are_created_from_iter_parts() {
+    let result = Fraction::from_iter_parts(vec![(2, 4), (3, -9), (0, 7)]);
+
+    let expected_result = Ok(vec![frac!(1, 2), frac!(-1, 3), frac!(0)]);
+    assert_eq!(expected_result, result);
+}
+
+#[test]
+fn from_iter_parts_err_at_first_zero_denominator() {
+    let result = Fraction::from_iter_parts(vec![(1, 2), (5, 0), (3, 0)]);
+
+    let expected_index = Some(1);
+    assert_eq!(expected_index, result.unwrap_err().index());
+}
+
+#[test]
+fn float_is_converted_to_exact_dyadic_fraction() {
+    assert_eq!(frac!(1, 2), Fraction64::closest_float_fraction(0.5));
+    assert_eq!(frac!(3), Fraction64::closest_float_fraction(3.0));
+    assert_eq!(frac!(-3, 4), Fraction64::closest_float_fraction(-0.75));
+
+    let expected_result = frac!(3602879701896397, 36028797018963968);
+    assert_eq!(expected_result, Fraction64::closest_float_fraction(0.1));
+
+    let min = -9_223_372_036_854_775_808.0;
+    assert_eq!(
+        Fraction64::new(i64::MIN, 1),
+        Fraction64::closest_float_fraction(min)
+    );
+}
+
+#[test]
+#[should_panic]
+fn float_conversion_should_panic_when_not_fitting() {
+    Fraction64::closest_float_fraction(9_223_372_036_854_775_808.0);
+}
+
+#[test]
+#[should_panic]
+fn float_conversion_should_panic_when_not_finite() {
+    Fraction64::closest_float_fraction(f64::NAN);
+}
+
 #[test]
 fn fraction_can_be_obtained_as_tuple() {
     let f = frac!(7, 23);
@@ -24,6 +87,14 @@ fn fraction_can_be_obtained_as_tuple() {
     assert_eq!(expected_tuple, f.get_as_tuple());
 }
 
+#[test]
+fn fraction_can_be_compared_to_tuple() {
+    assert!(frac!(1, 2).eq_tuple(2, 4));
+    assert!(frac!(-1, 2).eq_tuple(2, -4));
+    assert!(!frac!(1, 2).eq_tuple(1, 3));
+    assert!(!frac!(1, 2).eq_tuple(1, 0));
+}
+
 #[test]
 fn fraction_can_be_checked_for_properness() {
     let improper = frac!(10, 9);
@@ -60,6 +131,49 @@ fn fraction_is_displayed_correctly() {
     assert_eq!(expected_result, format!("{}", f));
 }
 
+#[test]
+fn fraction_is_displayed_with_precision() {
+    assert_eq!("0.333", format!("{:.3}", frac!(1, 3)));
+    assert_eq!("0.75", format!("{:.2}", frac!(3, 4)));
+    assert_eq!("0.667", format!("{:.3}", frac!(2, 3)));
+    assert_eq!("-2.3", format!("{:.1}", frac!(-7, 3)));
+    assert_eq!("1.00", format!("{:.2}", frac!(999, 1000)));
+    assert_eq!("1", format!("{:.0}", frac!(1, 2)));
+    assert_eq!("-128.00", format!("{:.2}", Fraction8::new(i8::MIN, 1)));
+    assert_eq!("3/4", format!("{}", frac!(3, 4)));
+}
+
+#[test]
+fn fraction_is_debug_formatted_correctly() {
+    let f = frac!(5, 17);
+
+    let expected_result = "Fraction(5/17)";
+    assert_eq!(expected_result, format!("{:?}", f));
+    assert_eq!("Fraction(-5/17)", format!("{:?}", -f));
+}
+
+#[test]
+fn fraction_is_split_into_mixed_parts() {
+    assert_eq!((2, frac!(1, 3)), frac!(7, 3).to_mixed());
+    assert_eq!((-2, frac!(-1, 3)), frac!(-7, 3).to_mixed());
+    assert_eq!((3, frac!(0)), frac!(3).to_mixed());
+}
+
+#[test]
+fn fraction_is_displayed_as_mixed_number() {
+    assert_eq!("2 1/3", format!("{:#}", frac!(7, 3)));
+    assert_eq!("-2 1/3", format!("{:#}", frac!(-7, 3)));
+    assert_eq!("-1/3", format!("{:#}", frac!(-1, 3)));
+    assert_eq!("4", format!("{:#}", frac!(8, 2)));
+}
+
+#[test]
+fn fraction_near_min_is_displayed_as_mixed_number() {
+    assert_eq!("-128", format!("{:#}", Fraction8::new(i8::MIN, 1)));
+    assert_eq!("-42 2/3", format!("{:#}", Fraction8::new(i8::MIN, 3)));
+    assert_eq!("-1 1/127", format!("{:#}", Fraction8::new(i8::MIN, 127)));
+}
+
 #[test]
 fn fraction_is_parsed_correctly() {
     let result = "5/17".parse::<Fraction>();
@@ -68,6 +182,21 @@ fn fraction_is_parsed_correctly() {
     assert_eq!(expected_result, result.ok());
 }
 
+#[test]
+fn fraction_is_parsed_from_bare_integer() {
+    assert_eq!(Some(frac!(5)), "5".parse::<Fraction>().ok());
+    assert_eq!(Some(frac!(-5)), "-5".parse::<Fraction>().ok());
+    assert_eq!(
+        Some(FractionParseError::IncorrectForm),
+        "5/".parse::<Fraction>().err()
+    );
+
+    let err = "300".parse::<Fraction8>().unwrap_err();
+    assert_eq!(Some(Component::Numerator), err.component());
+    assert_eq!(Some("300"), err.offending_text());
+    assert!(err.num_parse_error().is_some());
+}
+
 #[test]
 fn fraction_parse_err_when_incorrect_form() {
     let result = "5:17".parse::<Fraction>();
@@ -76,6 +205,23 @@ fn fraction_parse_err_when_incorrect_form() {
     assert_eq!(expected_result, result.err());
 }
 
+#[test]
+fn fraction_parse_err_when_component_is_empty() {
+    let expected_result = Some(FractionParseError::IncorrectForm);
+
+    assert_eq!(expected_result, "/5".parse::<Fraction>().err());
+    assert_eq!(expected_result, "5/".parse::<Fraction>().err());
+    assert_eq!(expected_result, "/".parse::<Fraction>().err());
+}
+
+#[test]
+fn fraction_parse_err_when_too_many_separators() {
+    let result = "1/2/3".parse::<Fraction>();
+
+    let expected_result = Some(FractionParseError::IncorrectForm);
+    assert_eq!(expected_result, result.err());
+}
+
 #[test]
 fn fraction_parse_err_when_zero_denominator() {
     let result = "1/0".parse::<Fraction>();
@@ -89,11 +235,29 @@ fn fraction_parse_err_when_number_cannot_be_parsed() {
     let result = "1/eight".parse::<Fraction>();
 
     assert!(
-        matches!(result.unwrap_err(), FractionParseError::NumParseError(_)),
+        matches!(
+            result.unwrap_err(),
+            FractionParseError::NumParseError { .. }
+        ),
         "Failed numeric parse did not yield a parse err"
     );
 }
 
+#[test]
+fn fraction_parse_err_reports_failed_component() {
+    let err = "x/2".parse::<Fraction>().unwrap_err();
+    assert_eq!(Some(Component::Numerator), err.component());
+    assert_eq!(Some("x"), err.offending_text());
+
+    let err = "2/x".parse::<Fraction>().unwrap_err();
+    assert_eq!(Some(Component::Denominator), err.component());
+    assert_eq!(Some("x"), err.offending_text());
+    assert!(err.num_parse_error().is_some());
+
+    let err = FractionParser::new(16).parse::<i32>("ff/zz").unwrap_err();
+    assert_eq!(Some(Component::Denominator), err.component());
+}
+
 #[test]
 fn sign_is_transferred_to_numerator() {
     let f = frac!(1, -5);
@@ -164,3 +328,1555 @@ fn fraction_should_panic_when_divided_by_zero() {
 
     f / g;
 }
+
+#[test]
+fn fraction_arithmetic_can_be_chained() {
+    let result = frac!(1, 2)
+        .added(frac!(1, 3))
+        .multiplied(frac!(2, 1))
+        .subtracted(frac!(1, 6))
+        .divided(frac!(3, 4));
+
+    let expected_result = ((frac!(1, 2) + frac!(1, 3)) * frac!(2, 1) - frac!(1, 6)) / frac!(3, 4);
+    assert_eq!(expected_result, result);
+}
+
+#[test]
+fn pairs_are_reduced_by_util() {
+    assert_eq!((7, 12), util::reduce(14, 24));
+    assert_eq!((3, 5), util::reduce(3, 5));
+    assert_eq!((0, 1), util::reduce(0, 17));
+}
+
+#[test]
+fn pairs_with_negative_denominator_are_reduced_by_util() {
+    assert_eq!((-1, 3), util::reduce(3, -9));
+    assert_eq!((-1, 3), util::reduce(-3, 9));
+    assert_eq!((1, 3), util::reduce(-3, -9));
+}
+
+#[test]
+fn pair_sign_is_normalized_by_util() {
+    assert_eq!((-1, 5), util::normalize_sign(1, -5));
+    assert_eq!((1, 5), util::normalize_sign(-1, -5));
+    assert_eq!((-1, 5), util::normalize_sign(-1, 5));
+}
+
+#[test]
+fn fraction_sign_is_returned_as_integer() {
+    assert_eq!(-1, frac!(-3, 7).sign());
+    assert_eq!(0, frac!(0).sign());
+    assert_eq!(1, frac!(3, 7).sign());
+}
+
+#[test]
+fn fraction_survives_le_bytes_round_trip() {
+    let f = Fraction16::new(-300, 7);
+    let bytes = f.to_le_bytes();
+
+    assert_eq!(vec![0xd4, 0xfe, 0x07, 0x00], bytes);
+    assert_eq!(Some(f), Fraction16::from_le_bytes(&bytes));
+
+    let g = Fraction64::new(-9_000_000_000, 11);
+    assert_eq!(Some(g), Fraction64::from_le_bytes(&g.to_le_bytes()));
+}
+
+#[test]
+fn fraction_from_le_bytes_rejects_invalid_input() {
+    assert_eq!(None, Fraction16::from_le_bytes(&[1, 0, 0, 0]));
+    assert_eq!(None, Fraction16::from_le_bytes(&[1, 0, 2]));
+}
+
+#[test]
+fn pair_is_normalized_in_place_by_util() {
+    let (mut n, mut d) = (-2, -4);
+    util::normalize(&mut n, &mut d);
+    assert_eq!((1, 2), (n, d));
+
+    let (mut n, mut d) = (0, 5);
+    util::normalize(&mut n, &mut d);
+    assert_eq!((0, 1), (n, d));
+}
+
+#[test]
+fn fractions_are_multiplied_by_integer_correctly() {
+    assert_eq!(frac!(2, 3), frac!(2, 9) * 3);
+    assert_eq!(frac!(-4, 3), frac!(2, 3) * -2);
+
+    let f = Fraction8::new(100, 127);
+    assert_eq!(Fraction8::new(100, 1), f * 127);
+}
+
+#[test]
+fn fractions_are_divided_by_integer_correctly() {
+    assert_eq!(frac!(2, 27), frac!(2, 9) / 3);
+    assert_eq!(frac!(-1, 3), frac!(2, 3) / -2);
+
+    let mut f = Fraction8::new(100, 3);
+    f /= 100;
+    assert_eq!(Fraction8::new(1, 3), f);
+}
+
+#[test]
+#[should_panic]
+fn fraction_should_panic_when_divided_by_zero_integer() {
+    let _ = frac!(3, 10) / 0;
+}
+
+#[test]
+fn fraction_is_rendered_in_radix() {
+    assert_eq!("ff/10", frac!(255, 16).to_string_radix(16));
+    assert_eq!("-ff/10", frac!(-255, 16).to_string_radix(16));
+    assert_eq!("-101/11", frac!(-5, 3).to_string_radix(2));
+    assert_eq!("0/1", frac!(0).to_string_radix(2));
+    assert_eq!("-80/1", Fraction8::new(i8::MIN, 1).to_string_radix(16));
+}
+
+#[test]
+fn fraction_parts_are_set_and_reduced() {
+    let mut f = frac!(1, 3);
+
+    assert!(f.set(2, 4).is_ok());
+    assert_eq!(frac!(1, 2), f);
+}
+
+#[test]
+fn fraction_set_err_when_zero_denominator() {
+    let mut f = frac!(1, 3);
+
+    assert!(f.set(2, 0).is_err());
+    assert_eq!(frac!(1, 3), f);
+}
+
+#[test]
+fn fraction_is_converted_to_primitive() {
+    assert_eq!(Some(0.5), frac!(1, 2).to_f64());
+    assert_eq!(Some(-0.25), Fraction64::new(-1, 4).to_f64());
+
+    assert_eq!(Some(2), frac!(7, 3).to_i64());
+    assert_eq!(Some(-2), frac!(-7, 3).to_i64());
+
+    assert_eq!(Some(2), frac!(7, 3).to_u64());
+    assert_eq!(None, frac!(-1, 3).to_u64());
+}
+
+#[test]
+fn fraction_is_converted_from_primitive() {
+    assert_eq!(Some(frac!(3)), Fraction::from_i64(3));
+    assert_eq!(Some(frac!(7)), Fraction::from_u32(7));
+    assert_eq!(None, Fraction8::from_u32(300));
+
+    assert_eq!(Some(frac!(1, 2)), Fraction::from_f64(0.5));
+    assert_eq!(Some(frac!(-3, 8)), Fraction::from_f32(-0.375));
+    assert_eq!(None, Fraction::<i32>::from_f64(f64::INFINITY));
+}
+
+#[test]
+fn fraction_is_wrapped_to_unit() {
+    assert_eq!(frac!(1, 3), frac!(7, 3).wrap_to_unit());
+    assert_eq!(frac!(-1, 3), frac!(-7, 3).wrap_to_unit());
+    assert_eq!(frac!(2, 5), frac!(2, 5).wrap_to_unit());
+    assert_eq!(frac!(0), frac!(-6, 3).wrap_to_unit());
+}
+
+#[test]
+fn fraction_midpoint_is_computed_correctly() {
+    assert_eq!(frac!(5, 12), frac!(1, 2).midpoint(&frac!(1, 3)));
+    assert_eq!(frac!(-1, 12), frac!(-1, 2).midpoint(&frac!(1, 3)));
+    assert_eq!(frac!(1, 2), frac!(0).midpoint(&frac!(1)));
+}
+
+#[test]
+fn fraction_midpoint_does_not_overflow() {
+    let f = Fraction16::new(30_000, 1);
+    let g = Fraction16::new(29_998, 1);
+    assert_eq!(Fraction16::new(29_999, 1), f.midpoint(&g));
+
+    let f = Fraction16::new(30_001, 2);
+    let g = Fraction16::new(29_999, 2);
+    assert_eq!(Fraction16::new(15_000, 1), f.midpoint(&g));
+
+    let f = Fraction16::new(-32_000, 3);
+    assert_eq!(f, f.midpoint(&f));
+}
+
+#[test]
+fn fraction_midpoint_with_odd_sum_does_not_overflow() {
+    let f = Fraction16::new(32_765, 3);
+    let g = Fraction16::new(32_764, 3);
+    assert_eq!(Fraction16::new(21_843, 2), f.midpoint(&g));
+
+    let f = Fraction8::new(-20, 1);
+    let g = Fraction8::new(-20, 7);
+    assert_eq!(Fraction8::new(-80, 7), f.midpoint(&g));
+
+    let f = Fraction8::new(127, 1);
+    let g = Fraction8::new(-128, 1);
+    assert_eq!(Fraction8::new(-1, 2), f.midpoint(&g));
+}
+
+#[test]
+fn fractions_in_range_are_enumerated() {
+    let result: Vec<_> = Fraction::range_with_max_denominator(frac!(0), frac!(1), 4).collect();
+
+    let expected_result = vec![
+        frac!(0),
+        frac!(1, 4),
+        frac!(1, 3),
+        frac!(1, 2),
+        frac!(2, 3),
+        frac!(3, 4),
+        frac!(1),
+    ];
+    assert_eq!(expected_result, result);
+}
+
+#[test]
+fn fractions_in_range_spanning_zero_are_enumerated() {
+    let result: Vec<_> =
+        Fraction::range_with_max_denominator(frac!(-2, 3), frac!(1, 2), 2).collect();
+
+    let expected_result = vec![frac!(-1, 2), frac!(0), frac!(1, 2)];
+    assert_eq!(expected_result, result);
+}
+
+#[test]
+fn fractions_in_range_near_max_are_enumerated_without_overflow() {
+    let low = Fraction16::new(1000, 1);
+    let high = Fraction16::new(1001, 1);
+    let result: Vec<_> = Fraction::range_with_max_denominator(low, high, 100).collect();
+
+    // 1000 + k/33 and beyond do not fit in an i16, so only denominators up to 32 are yielded
+    assert_eq!(325, result.len());
+    assert_eq!(Fraction16::new(32_001, 32), result[1]);
+    assert_eq!(Fraction16::new(32_031, 32), result[323]);
+    assert_eq!(high, result[324]);
+
+    let low = Fraction16::new(32_000, 1);
+    let high = Fraction16::new(32_767, 1);
+    let result: Vec<_> = Fraction::range_with_max_denominator(low, high, 1).collect();
+
+    assert_eq!(768, result.len());
+    assert_eq!(Some(&high), result.last());
+}
+
+#[test]
+fn fractions_are_widened_losslessly() {
+    let f = Fraction8::new(-3, 7);
+
+    let g: Fraction16 = f.into();
+    assert_eq!(Fraction16::new(-3, 7), g);
+
+    let h: Fraction32 = g.into();
+    assert_eq!(Fraction32::new(-3, 7), h);
+
+    let i: Fraction64 = h.into();
+    assert_eq!(Fraction64::new(-3, 7), i);
+
+    assert_eq!(i, Fraction64::from(f));
+    assert_eq!(i, Fraction64::from(g));
+    assert_eq!(h, Fraction32::from(f));
+}
+
+#[test]
+fn fractions_are_added_and_subtracted_fallibly() {
+    assert_eq!(Ok(frac!(11, 70)), frac!(1, 14).try_add(&frac!(3, 35)));
+    assert_eq!(Ok(frac!(2, 35)), frac!(1, 7).try_sub(&frac!(3, 35)));
+
+    let f = Fraction8::new(100, 1);
+    assert_eq!(Err(ArithmeticError::Overflow), f.try_add(&f));
+    assert_eq!(Err(ArithmeticError::Overflow), (-f).try_sub(&f));
+}
+
+#[test]
+fn fractions_are_multiplied_and_divided_fallibly() {
+    assert_eq!(Ok(frac!(12, 143)), frac!(3, 13).try_mul(&frac!(4, 11)));
+    assert_eq!(Ok(frac!(-8, 95)), frac!(1, 19).try_div(&frac!(-5, 8)));
+
+    let f = Fraction8::new(100, 3);
+    assert_eq!(Ok(Fraction8::new(100, 1)), f.try_mul(&Fraction8::new(3, 1)));
+    assert_eq!(Err(ArithmeticError::Overflow), f.try_mul(&f));
+    assert_eq!(
+        Err(ArithmeticError::Overflow),
+        f.try_div(&Fraction8::new(1, 100))
+    );
+}
+
+#[test]
+fn fraction_try_div_err_when_divided_by_zero() {
+    let result = frac!(3, 10).try_div(&frac!(0));
+
+    assert_eq!(Err(ArithmeticError::DivisionByZero), result);
+}
+
+#[test]
+fn fraction_try_div_handles_min_numerators() {
+    let min = Fraction8::new(-128, 1);
+
+    assert_eq!(Ok(Fraction8::new(1, 1)), min.try_div(&min));
+    assert_eq!(Ok(Fraction8::new(0, 1)), Fraction8::new(0, 1).try_div(&min));
+    assert_eq!(
+        Ok(Fraction8::new(-128, 37)),
+        Fraction8::new(48, 1).try_div(&Fraction8::new(-111, 8))
+    );
+}
+
+#[test]
+fn fraction_is_parsed_from_any_spelling() {
+    let expected_result = Ok(frac!(3, 4));
+
+    assert_eq!(expected_result, Fraction::parse_flexible("3/4"));
+    assert_eq!(expected_result, Fraction::parse_flexible("6/8"));
+    assert_eq!(expected_result, Fraction::parse_flexible("0.75"));
+    assert_eq!(expected_result, Fraction::parse_flexible("75%"));
+
+    assert_eq!(Ok(frac!(-1, 8)), Fraction::parse_flexible("-12.5%"));
+    assert_eq!(Ok(frac!(2)), Fraction::parse_flexible("2"));
+}
+
+#[test]
+fn fraction_parse_flexible_err_when_invalid() {
+    assert!(Fraction::<i32>::parse_flexible("three quarters").is_err());
+    assert!(Fraction::<i32>::parse_flexible("0.7.5").is_err());
+    assert!(Fraction::<i32>::parse_flexible("1.-5").is_err());
+    assert!(Fraction::<i32>::parse_flexible("75%%").is_err());
+}
+
+#[test]
+fn min_fraction_heap_pops_in_ascending_order() {
+    let fractions = [frac!(1, 2), frac!(-1, 3), frac!(3, 4), frac!(1, 5)];
+    let mut heap: BinaryHeap<_> = fractions.iter().copied().map(MinFraction).collect();
+
+    let result: Vec<_> = std::iter::from_fn(|| heap.pop().map(|MinFraction(f)| f)).collect();
+
+    let expected_result = vec![frac!(-1, 3), frac!(1, 5), frac!(1, 2), frac!(3, 4)];
+    assert_eq!(expected_result, result);
+}
+
+#[test]
+fn fraction_is_reciprocated_fallibly() {
+    assert_eq!(Some(frac!(-5, 3)), frac!(-3, 5).checked_reciprocal());
+    assert_eq!(None, frac!(0).checked_reciprocal());
+
+    assert_eq!(None, Fraction8::new(i8::MIN, 3).checked_reciprocal());
+    assert_eq!(
+        Some(Fraction8::new(-3, 127)),
+        Fraction8::new(-127, 3).checked_reciprocal()
+    );
+    assert_eq!(
+        Fraction8::new(-3, 127),
+        Fraction8::new(-127, 3).reciprocal()
+    );
+}
+
+#[test]
+fn fraction_is_raised_to_integer_power_fallibly() {
+    assert_eq!(Some(frac!(-8, 27)), frac!(-2, 3).checked_pow(3));
+    assert_eq!(Some(frac!(1)), frac!(-2, 3).checked_pow(0));
+    assert_eq!(Some(frac!(9, 4)), frac!(-2, 3).checked_pow(-2));
+    assert_eq!(Some(frac!(-27, 8)), frac!(-2, 3).checked_pow(-3));
+}
+
+#[test]
+fn fraction_checked_pow_none_when_invalid() {
+    assert_eq!(None, Fraction8::new(2, 3).checked_pow(5));
+    assert_eq!(None, Fraction8::new(3, 2).checked_pow(-5));
+    assert_eq!(None, frac!(0).checked_pow(-1));
+}
+
+#[test]
+fn telescoping_series_matches_closed_form() {
+    for n in 1..=20 {
+        assert_eq!(frac!(n, n + 1), Fraction::sum_reciprocals_product(n));
+    }
+
+    assert_eq!(frac!(0), Fraction::sum_reciprocals_product(0));
+}
+
+#[test]
+fn fraction_is_approximated_with_bounded_denominator() {
+    let pi = Fraction64::new(3_141_592_653, 1_000_000_000);
+
+    assert_eq!(Fraction64::new(3, 1), pi.approximate(1));
+    assert_eq!(Fraction64::new(22, 7), pi.approximate(10));
+    assert_eq!(Fraction64::new(355, 113), pi.approximate(200));
+    assert_eq!(Fraction64::new(-22, 7), (-pi).approximate(10));
+    assert_eq!(frac!(1, 3), frac!(1, 3).approximate(10));
+}
+
+#[test]
+fn fraction_with_large_parts_is_approximated_without_overflow() {
+    let tiny = Fraction64::new(-6, 730_287_448_461_418_315);
+    let tau = Fraction64::new(-6_283_185_307_179_586_477, 2_000_000_000_000_000_001);
+
+    assert_eq!(frac!(0, 1), tiny.approximate(4_377_437_462_094_310));
+    assert_eq!(Fraction64::new(-355, 113), tau.approximate(1000));
+    assert_eq!(
+        Fraction64::new(-1_068_966_896, 340_262_731),
+        tau.approximate(1_000_000_000)
+    );
+    assert_eq!(
+        Fraction64::new(-1_695_409_250_706_334, 539_665_525_627_279),
+        tau.approximate(4_377_437_462_094_310)
+    );
+    assert_eq!(
+        Some(frac!(0, 1)),
+        tiny.nearest_simple(4_377_437_462_094_310, Fraction64::new(1, 100))
+    );
+}
+
+#[test]
+fn fraction_snaps_to_nearest_simple_fraction() {
+    let f = frac!(333, 1000);
+
+    assert_eq!(Some(frac!(1, 3)), f.nearest_simple(10, frac!(1, 100)));
+    assert_eq!(None, f.nearest_simple(10, frac!(1, 10000)));
+}
+
+#[test]
+fn fraction_can_be_checked_for_range() {
+    let low = frac!(-1, 2);
+    let high = frac!(3, 4);
+
+    assert!(frac!(1, 3).is_in_range(&low, &high));
+    assert!(low.is_in_range(&low, &high));
+    assert!(high.is_in_range(&low, &high));
+    assert!(!frac!(4, 5).is_in_range(&low, &high));
+    assert!(!frac!(-2, 3).is_in_range(&low, &high));
+}
+
+#[test]
+fn integer_is_raised_to_fractional_power() {
+    assert_eq!(Some(frac!(2)), Fraction::int_pow_frac(8, frac!(1, 3), 10));
+    assert_eq!(Some(frac!(-2)), Fraction::int_pow_frac(-8, frac!(1, 3), 10));
+    assert_eq!(
+        Some(frac!(1, 2)),
+        Fraction::int_pow_frac(4, frac!(-1, 2), 10)
+    );
+    assert_eq!(
+        Some(frac!(140, 99)),
+        Fraction::int_pow_frac(2, frac!(1, 2), 100)
+    );
+    assert_eq!(
+        Some(frac!(181, 39)),
+        Fraction::int_pow_frac(10, frac!(2, 3), 50)
+    );
+}
+
+#[test]
+fn integer_fractional_power_none_when_undefined() {
+    assert_eq!(None, Fraction::int_pow_frac(-4, frac!(1, 2), 10));
+    assert_eq!(None, Fraction::int_pow_frac(0, frac!(-1, 2), 10));
+}
+
+#[test]
+fn integer_fractional_power_none_when_not_fitting() {
+    let root = Fraction::<i16>::int_pow_frac(5000, Fraction::new(1, 2), 100);
+    assert_eq!(Some(Fraction::new(2687, 38)), root);
+
+    assert_eq!(
+        None,
+        Fraction::<i16>::int_pow_frac(5000, Fraction::new(1, 2), 1000)
+    );
+    assert_eq!(
+        None,
+        Fraction::<i16>::int_pow_frac(30000, Fraction::new(1, 2), 1000)
+    );
+}
+
+#[test]
+fn fractions_of_different_widths_are_added() {
+    let f = Fraction8::new(1, 3);
+    let g = Fraction64::new(5_000_000_000, 7);
+
+    let expected_result = Fraction64::new(15_000_000_007, 21);
+    assert_eq!(expected_result, g + f);
+    assert_eq!(expected_result, f + g);
+
+    let h: Fraction16 = Fraction16::new(1, 6) + f;
+    assert_eq!(Fraction16::new(1, 2), h);
+}
+
+#[test]
+fn fractions_are_divided_euclidean() {
+    let cases = [
+        (frac!(7, 2), frac!(2, 3), 5),
+        (frac!(-7, 2), frac!(2, 3), -6),
+        (frac!(7, 2), frac!(-2, 3), -5),
+        (frac!(-7, 2), frac!(-2, 3), 6),
+        (frac!(4), frac!(2, 3), 6),
+    ];
+
+    for &(f, g, expected_quotient) in cases.iter() {
+        let quotient = f.div_euclid(&g);
+        assert_eq!(expected_quotient, quotient);
+
+        let remainder = f - g * quotient;
+        assert!(remainder >= frac!(0));
+        assert!(remainder < frac!(2, 3));
+    }
+}
+
+#[test]
+fn fractions_remainder_is_euclidean() {
+    assert_eq!(frac!(1, 2), frac!(-1, 2).rem_euclid(&frac!(1)));
+    assert_eq!(frac!(1, 2), frac!(-1, 2).rem_euclid(&frac!(-1)));
+    assert_eq!(frac!(1, 6), frac!(7, 2).rem_euclid(&frac!(2, 3)));
+
+    let divisors = [frac!(2, 3), frac!(-2, 3)];
+    let dividends = [frac!(7, 2), frac!(-7, 2), frac!(0), frac!(-4)];
+    for g in divisors.iter() {
+        for f in dividends.iter() {
+            let remainder = f.rem_euclid(g);
+
+            assert!(remainder >= frac!(0));
+            assert!(remainder < frac!(2, 3));
+            assert_eq!(*f, *g * f.div_euclid(g) + remainder);
+        }
+    }
+}
+
+#[test]
+fn fraction_is_parsed_in_radix() {
+    let hex = FractionParser::new(16);
+    assert_eq!(Ok(frac!(255, 16)), hex.parse::<i32>("ff/10"));
+    assert_eq!(Ok(frac!(-1, 16)), hex.parse::<i32>("-1/10"));
+
+    let binary = FractionParser::new(2);
+    assert_eq!(Ok(frac!(5, 3)), binary.parse::<i32>("101/11"));
+    assert_eq!(Ok(frac!(1, 2)), binary.parse::<i32>("10/100"));
+
+    assert_eq!(
+        Ok(frac!(5, 17)),
+        FractionParser::default().parse::<i32>("5/17")
+    );
+}
+
+#[test]
+fn fraction_radix_parse_err_when_invalid() {
+    let binary = FractionParser::new(2);
+
+    assert!(binary
+        .parse::<i32>("12/1")
+        .unwrap_err()
+        .is_num_parse_error());
+    assert!(binary.parse::<i32>("1:1").unwrap_err().is_incorrect_form());
+    assert!(binary
+        .parse::<i32>("1/0")
+        .unwrap_err()
+        .is_zero_denominator());
+}
+
+#[test]
+fn fractions_are_summed_over_fixed_denominator() {
+    assert_eq!(
+        Some((75, 100)),
+        Fraction::sum_over(&[frac!(1, 4), frac!(1, 2)], 100)
+    );
+    assert_eq!(
+        Some((-15, 100)),
+        Fraction::sum_over(&[frac!(1, 20), frac!(-1, 5)], 100)
+    );
+    assert_eq!(Some((0, 100)), Fraction::sum_over(&[], 100));
+}
+
+#[test]
+fn fractions_sum_over_none_when_not_representable() {
+    assert_eq!(None, Fraction::sum_over(&[frac!(1, 4), frac!(1, 3)], 100));
+
+    let f = Fraction8::new(3, 4);
+    assert_eq!(None, Fraction8::sum_over(&[f, f], 100));
+}
+
+#[test]
+fn fractions_are_spaced_linearly() {
+    let result = Fraction::linspace(frac!(0), frac!(1), 5);
+
+    let expected_result = vec![frac!(0), frac!(1, 4), frac!(1, 2), frac!(3, 4), frac!(1)];
+    assert_eq!(expected_result, result);
+
+    let result = Fraction::linspace(frac!(1, 3), frac!(-1, 3), 3);
+    assert_eq!(vec![frac!(1, 3), frac!(0), frac!(-1, 3)], result);
+}
+
+#[test]
+fn fractions_linspace_edge_cases() {
+    assert_eq!(
+        vec![frac!(1, 3)],
+        Fraction::linspace(frac!(1, 3), frac!(1), 1)
+    );
+    assert!(Fraction::linspace(frac!(1, 3), frac!(1), 0).is_empty());
+}
+
+#[test]
+fn fraction_denominators_lcm_is_computed() {
+    let fractions = vec![frac!(1, 2), frac!(1, 3), frac!(1, 4)];
+    assert_eq!(12, Fraction::lcm_denominators(fractions));
+
+    assert_eq!(1, Fraction::<i32>::lcm_denominators(vec![]));
+}
+
+#[test]
+#[should_panic]
+fn fraction_denominators_lcm_should_panic_on_overflow() {
+    let fractions = vec![Fraction8::new(1, 11), Fraction8::new(1, 13)];
+    Fraction8::lcm_denominators(fractions);
+}
+
+#[test]
+fn fraction_decimal_digits_are_streamed() {
+    let result: Vec<_> = frac!(1, 7).decimal_digits().take(8).collect();
+    assert_eq!(vec![0, 1, 4, 2, 8, 5, 7, 1], result);
+
+    let result: Vec<_> = frac!(-25, 4).decimal_digits().collect();
+    assert_eq!(vec![6, 2, 5], result);
+
+    let result: Vec<_> = Fraction8::new(1, 127).decimal_digits().take(6).collect();
+    assert_eq!(vec![0, 0, 0, 7, 8, 7], result);
+}
+
+#[test]
+fn fraction_is_converted_from_ratio() {
+    assert_eq!(
+        Some(frac!(-2, 3)),
+        Fraction::try_from_ratio(Ratio::new_raw(4, -6))
+    );
+    assert_eq!(
+        Some(frac!(1, 2)),
+        Fraction::try_from_ratio(Ratio::new(3, 6))
+    );
+
+    let ratio = Ratio::new_raw(i8::MIN, -2);
+    assert_eq!(
+        Some(Fraction8::new(64, 1)),
+        Fraction8::try_from_ratio(ratio)
+    );
+}
+
+#[test]
+fn fraction_from_ratio_none_on_overflow() {
+    assert_eq!(None, Fraction8::try_from_ratio(Ratio::new_raw(i8::MIN, -1)));
+    assert_eq!(None, Fraction8::try_from_ratio(Ratio::new_raw(1, 0)));
+}
+
+#[test]
+fn fractions_are_stepped_exactly() {
+    let result: Vec<_> = Fraction::step_by(frac!(0), frac!(1, 3), 4).collect();
+    assert_eq!(vec![frac!(0), frac!(1, 3), frac!(2, 3), frac!(1)], result);
+
+    let result: Vec<_> = Fraction::step_by(frac!(1, 2), frac!(-1, 4), 3).collect();
+    assert_eq!(vec![frac!(1, 2), frac!(1, 4), frac!(0)], result);
+
+    assert_eq!(0, Fraction::step_by(frac!(0), frac!(1), 0).count());
+}
+
+#[test]
+fn unsigned_fractions_are_constructed() {
+    let f = FractionU32::new(18, 512);
+    assert_eq!((9, 256), f.get_as_tuple());
+
+    let result = FractionU64::from_iter_parts(vec![(4, 6), (0, 9)]);
+    assert_eq!(
+        Ok(vec![FractionU64::new(2, 3), FractionU64::new(0, 1)]),
+        result
+    );
+}
+
+#[test]
+fn unsigned_fractions_are_added_and_compared() {
+    let f = FractionU64::new(1, 14);
+    let g = FractionU64::new(3, 35);
+
+    assert_eq!(FractionU64::new(11, 70), f + g);
+    assert_eq!(FractionU64::new(3, 490), f * g);
+    assert_eq!(FractionU64::new(6, 5), g / f);
+    assert!(f < g);
+
+    let h = FractionU32::new(u32::MAX - 1, 2);
+    assert_eq!(
+        "2147483647 1/2",
+        format!("{:#}", h + FractionU32::new(1, 2))
+    );
+}
+
+#[test]
+fn unsigned_magnitude_fractions_exceed_signed_range() {
+    let f = UnsignedFraction::<u8>::new(false, 200, 1);
+    let g = UnsignedFraction::<u8>::new(false, 50, 1);
+
+    assert_eq!(UnsignedFraction::new(false, 250, 1), f + g);
+    assert_eq!(UnsignedFraction::new(true, 150, 1), g - f);
+    assert_eq!(UnsignedFraction::new(true, 250, 1), -f - g);
+    assert_eq!(UnsignedFraction::new(false, 4, 1), f / g);
+    assert_eq!(
+        UnsignedFraction::new(true, 200, 3),
+        -f * UnsignedFraction::new(false, 1, 3)
+    );
+
+    assert!(-f < g);
+    assert_eq!("-50/1", format!("{}", -g));
+}
+
+#[test]
+fn unsigned_magnitude_fractions_convert_to_and_from_signed() {
+    let f = UnsignedFraction::<u8>::from(Fraction8::new(-128, 3));
+    assert_eq!(UnsignedFraction::new(true, 128, 3), f);
+    assert_eq!(Ok(Fraction8::new(-128, 3)), Fraction8::try_from(f));
+
+    let g = UnsignedFraction::<u8>::new(false, 200, 3);
+    assert!(Fraction8::try_from(g).is_err());
+
+    assert_eq!(
+        UnsignedFraction::new(false, 0, 1),
+        -UnsignedFraction::<u32>::new(false, 0, 5)
+    );
+}
+
+#[test]
+fn scaler_matches_reduced_product() {
+    let factors = [(5, 9), (-12, 7), (0, 1), (3, 1)];
+
+    for &(factor_num, factor_den) in factors.iter() {
+        let scaler = Scaler::new(frac!(factor_num, factor_den));
+
+        for n in -30..=30 {
+            for d in 1..=30 {
+                // reduce the full product instead of cross-reducing
+                let expected = frac!(n * factor_num, d * factor_den);
+                assert_eq!(expected, scaler.apply(frac!(n, d)));
+            }
+        }
+    }
+
+    // the full product 300/300 does not fit in 8 bits, but the cross-reduced one does
+    let scaler = Scaler::new(Fraction8::new(100, 3));
+    assert_eq!(Fraction8::new(1, 1), scaler.apply(Fraction8::new(3, 100)));
+}
+
+#[test]
+fn fraction_positive_difference_is_computed() {
+    assert_eq!(frac!(0), frac!(1, 3).abs_sub(&frac!(1, 2)));
+    assert_eq!(frac!(1, 6), frac!(1, 2).abs_sub(&frac!(1, 3)));
+    assert_eq!(frac!(0), frac!(1, 2).abs_sub(&frac!(1, 2)));
+}
+
+#[test]
+fn fraction_is_raised_to_power_with_reduction() {
+    assert_eq!(frac!(-8, 27), frac!(-2, 3).pow_reduced(3));
+    assert_eq!(frac!(1), frac!(-2, 3).pow_reduced(0));
+
+    for &f in [frac!(-2, 3), frac!(5, 7), frac!(-1, 2), frac!(11, 4)].iter() {
+        for exp in 0..=8 {
+            let expected = (0..exp).fold(frac!(1), |acc, _| acc * f);
+            assert_eq!(expected, f.pow_reduced(exp));
+        }
+    }
+
+    // the largest power of two whose reciprocal fits in 16 bits
+    assert_eq!(
+        Fraction16::new(1, 16_384),
+        Fraction16::new(1, 2).pow_reduced(14)
+    );
+}
+
+#[test]
+fn fraction64_is_converted_to_correctly_rounded_f64() {
+    // naive float division misrounds each of these by one ulp
+    let f = Fraction64::new(5_525_293_661_586_882_813, 6_510_336_435_263_845_543);
+    assert_eq!(0.848_695_565_356_440_5, f.to_f64_correct());
+
+    let f = Fraction64::new(-6_222_374_259_250_058_869, 4_815_841_419_668_375_729);
+    assert_eq!(-1.292_063_778_063_219_1, f.to_f64_correct());
+
+    let f = Fraction64::new(9_040_985_244_004_255_137, 5_037_330_906_685_115_791);
+    assert_eq!(1.794_796_770_648_088_7, f.to_f64_correct());
+
+    // ties are rounded to even
+    assert_eq!(
+        9_007_199_254_740_992.0,
+        Fraction64::new(9_007_199_254_740_993, 1).to_f64_correct()
+    );
+    assert_eq!(
+        9_007_199_254_740_996.0,
+        Fraction64::new(9_007_199_254_740_995, 1).to_f64_correct()
+    );
+
+    assert_eq!(0.0, Fraction64::new(0, 7).to_f64_correct());
+    assert_eq!(-0.75, Fraction64::new(-3, 4).to_f64_correct());
+}
+
+#[test]
+fn boxed_rational_values_are_sorted() {
+    let mut values: Vec<Box<dyn RationalValue>> = vec![
+        Box::new(Fraction64::new(i64::MAX, i64::MAX - 1)),
+        Box::new(Fraction16::new(-1, 2)),
+        Box::new(Fraction64::new(1, 3)),
+        Box::new(Fraction16::new(1, 1)),
+        Box::new(Fraction64::new(-2, 3)),
+    ];
+
+    values.sort_by(|a, b| a.cmp_rational(b.as_ref()));
+
+    let expected_result = vec![
+        (-2, 3),
+        (-1, 2),
+        (1, 3),
+        (1, 1),
+        (i64::MAX.into(), (i64::MAX - 1).into()),
+    ];
+    let sorted: Vec<(i128, i128)> = values.iter().map(|v| v.to_i128_parts()).collect();
+    assert_eq!(expected_result, sorted);
+    assert_eq!(-0.5, values[1].as_f64());
+}
+
+#[test]
+fn rational_values_with_128_bit_parts_are_compared() {
+    let f = Fraction::<i128>::new(i128::MAX, 1);
+    let g = Fraction::<i128>::new(i128::MAX - 2, 3);
+
+    assert_eq!(Ordering::Greater, f.cmp_rational(&g));
+    assert_eq!(Ordering::Less, g.cmp_rational(&f));
+    assert_eq!(Ordering::Equal, f.cmp_rational(&f));
+}
+
+#[test]
+fn reduction_factor_is_the_divided_out_gcd() {
+    assert_eq!(50, util::reduction_factor(50, 100));
+    assert_eq!(1, util::reduction_factor(7, 13));
+}
+
+#[test]
+fn fractions_are_summed_with_overflow_check() {
+    let fracs = [frac!(1, 2), frac!(1, 3), frac!(1, 6)];
+    assert_eq!(Some(frac!(1)), Fraction::checked_sum(&fracs));
+    assert_eq!(Some(frac!(0)), Fraction::<i32>::checked_sum(&[]));
+
+    let fracs = [
+        Fraction8::new(100, 1),
+        Fraction8::new(1, 2),
+        Fraction8::new(27, 1),
+    ];
+    assert_eq!(None, Fraction8::checked_sum(&fracs));
+}
+
+#[test]
+fn fraction_subtraction_near_min_is_checked() {
+    let min = Fraction8::new(i8::MIN, 1);
+
+    assert_eq!(
+        Some(Fraction8::new(127, 1)),
+        Fraction8::new(-1, 1).checked_sub(&min)
+    );
+    assert_eq!(
+        Some(min),
+        Fraction8::new(-127, 1).checked_sub(&Fraction8::new(1, 1))
+    );
+    assert_eq!(None, Fraction8::new(0, 1).checked_sub(&min));
+    assert_eq!(None, min.checked_sub(&Fraction8::new(1, 2)));
+}
+
+#[test]
+fn fibonacci_ratios_are_golden_ratio_convergents() {
+    let expected_result = vec![
+        Fraction64::new(1, 1),
+        Fraction64::new(2, 1),
+        Fraction64::new(3, 2),
+        Fraction64::new(5, 3),
+        Fraction64::new(8, 5),
+    ];
+    let ratios: Vec<Fraction64> = (1..=5).map(Fraction64::fibonacci_ratio).collect();
+    assert_eq!(expected_result, ratios);
+
+    let f = Fraction64::fibonacci_ratio(91);
+    assert_eq!(7_540_113_804_746_346_429, f.numerator());
+}
+
+#[test]
+#[should_panic]
+fn fibonacci_ratio_panics_for_zero() {
+    Fraction64::fibonacci_ratio(0);
+}
+
+#[test]
+fn fraction_soa_reduces_like_new() {
+    let numerators = vec![14, 3, 0, -6, 5];
+    let denominators = vec![24, -9, 7, -4, 5];
+
+    let expected_result: Vec<Fraction> = numerators
+        .iter()
+        .zip(&denominators)
+        .map(|(&n, &d)| Fraction::new(n, d))
+        .collect();
+
+    let mut soa = FractionSoa::new(numerators, denominators);
+    soa.reduce_all();
+
+    assert_eq!(&[7, -1, 0, 3, 1], soa.numerators());
+    assert_eq!(&[12, 3, 1, 2, 1], soa.denominators());
+    assert_eq!(expected_result, Vec::from(soa));
+}
+
+#[test]
+fn fraction_soa_round_trips_fractions() {
+    let fracs = vec![frac!(1, 2), frac!(-3, 4)];
+    let soa = FractionSoa::from(fracs.clone());
+
+    assert_eq!(2, soa.len());
+    assert_eq!(fracs, Vec::from(soa));
+}
+
+#[test]
+fn fraction_absolute_value_is_checked() {
+    assert_eq!(None, Fraction8::new(i8::MIN, 1).checked_abs());
+    assert_eq!(
+        Some(Fraction8::new(i8::MAX, 3)),
+        Fraction8::new(-i8::MAX, 3).checked_abs()
+    );
+    assert_eq!(Some(frac!(1, 2)), frac!(1, 2).checked_abs());
+}
+
+#[test]
+fn fraction_comparison_does_not_overflow() {
+    // cross products such as 30000 * 29999 do not fit in an i16
+    let a = Fraction16::new(29_999, 30_000);
+    let b = Fraction16::new(29_998, 29_999);
+    assert!(a > b);
+    assert!(Fraction16::new(-29_999, 30_000) < -b);
+
+    let c = Fraction8::new(126, 127);
+    let d = Fraction8::new(125, 126);
+    assert!(d < c);
+    assert_eq!(
+        std::cmp::Ordering::Equal,
+        Fraction8::new(-127, 2).cmp(&Fraction8::new(-127, 2))
+    );
+
+    let e = Fraction64::new(i64::MAX, i64::MAX - 1);
+    let f = Fraction64::new(i64::MAX - 1, i64::MAX - 2);
+    assert!(e < f);
+}
+
+#[test]
+fn fraction_is_created_from_exact_f32() {
+    assert_eq!(Some(Fraction64::new(1, 2)), Fraction64::from_exact_f32(0.5));
+    assert_eq!(
+        Some(Fraction64::new(1, 4)),
+        Fraction64::from_exact_f32(0.25)
+    );
+    assert_eq!(
+        Some(Fraction64::new(13_421_773, 134_217_728)),
+        Fraction64::from_exact_f32(0.1)
+    );
+    assert_eq!(None, Fraction64::from_exact_f32(f32::NAN));
+    assert_eq!(None, Fraction64::from_exact_f32(f32::INFINITY));
+}
+
+#[test]
+fn fraction_weighted_mean_is_computed() {
+    let values = [frac!(1, 2), frac!(2)];
+    let weights = [frac!(3, 4), frac!(1, 4)];
+    assert_eq!(
+        Some(frac!(7, 8)),
+        Fraction::weighted_mean(&values, &weights)
+    );
+
+    assert_eq!(None, Fraction::weighted_mean(&values, &weights[..1]));
+    assert_eq!(
+        None,
+        Fraction::weighted_mean(&values, &[frac!(1), frac!(-1)])
+    );
+}
+
+#[test]
+fn fraction_is_scaled_by_integer_with_overflow_check() {
+    assert_eq!(
+        Some(Fraction8::new(5, 2)),
+        Fraction8::new(5, 6).checked_mul_int(3)
+    );
+    assert_eq!(
+        Some(Fraction8::new(-5, 18)),
+        Fraction8::new(5, 6).checked_div_int(-3)
+    );
+    assert_eq!(
+        Some(Fraction8::new(1, 3)),
+        Fraction8::new(2, 3).checked_div_int(2)
+    );
+
+    assert_eq!(None, Fraction8::new(100, 3).checked_mul_int(2));
+    assert_eq!(None, Fraction8::new(1, 100).checked_div_int(3));
+    assert_eq!(None, Fraction8::new(1, 2).checked_div_int(0));
+
+    assert_eq!(
+        Some(Fraction8::new(1, 1)),
+        Fraction8::new(-128, 1).checked_div_int(-128)
+    );
+    assert_eq!(None, Fraction8::new(-128, 1).checked_div_int(-1));
+}
+
+#[test]
+fn fraction_is_converted_to_exact_integer() {
+    assert_eq!(Some(2), frac!(6, 3).try_into_integer());
+    assert_eq!(Some(-4), frac!(-4).try_into_integer());
+    assert_eq!(None, frac!(1, 2).try_into_integer());
+}
+
+#[test]
+fn fraction_is_converted_to_finite_f64() {
+    assert_eq!(Some(0.75), Fraction64::new(3, 4).to_f64_finite());
+    assert_eq!(Some(0.0), Fraction64::new(0, 4).to_f64_finite());
+
+    // even the most extreme 64-bit fractions stay well within the f64 range
+    let extreme = Fraction64::new(i64::MIN, 1).to_f64_finite();
+    assert_eq!(Some(-9_223_372_036_854_775_808.0), extreme);
+}
+
+#[test]
+fn fraction_convergents_are_streamed() {
+    let f = frac!(415, 93);
+    let mut convergents = f.convergent_stream();
+
+    assert_eq!(Some(frac!(4)), convergents.next());
+    assert_eq!(Some(frac!(9, 2)), convergents.next());
+    assert_eq!(Some(frac!(58, 13)), convergents.next());
+    assert_eq!(Some(f), convergents.next());
+    assert_eq!(None, convergents.next());
+
+    let expected_result = vec![frac!(-1), frac!(-2, 3)];
+    assert_eq!(
+        expected_result,
+        frac!(-2, 3).convergent_stream().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn stern_brocot_mediant_and_subtree_are_found() {
+    let (left, right) = (frac!(0, 1), frac!(1, 1));
+    let mediant = Fraction::mediant_between(&left, &right);
+    assert_eq!(frac!(1, 2), mediant);
+
+    let f = frac!(1, 3);
+    assert!(f.contains_in_subtree(&left, &right));
+    assert!(f.contains_in_subtree(&left, &mediant));
+    assert!(!f.contains_in_subtree(&mediant, &right));
+    assert!(!mediant.contains_in_subtree(&mediant, &right));
+}
+
+#[test]
+fn shares_are_rounded_by_largest_remainder() {
+    let thirds = [frac!(1, 3), frac!(1, 3), frac!(1, 3)];
+    let shares = Fraction::round_shares(&thirds, 10);
+    assert_eq!(vec![4, 3, 3], shares);
+    assert_eq!(10, shares.iter().sum::<i32>());
+
+    // weights are normalized by their sum, 2/7 : 3/7 : 2/7 of 100
+    let weights = [frac!(1, 5), frac!(3, 10), frac!(1, 5)];
+    assert_eq!(vec![29, 43, 28], Fraction::round_shares(&weights, 100));
+
+    let shares = Fraction::round_shares(&thirds, -10);
+    assert_eq!(vec![-3, -3, -4], shares);
+    assert_eq!(-10, shares.iter().sum::<i32>());
+}
+
+#[test]
+fn integer_is_added_and_subtracted_with_overflow_check() {
+    assert_eq!(Some(frac!(7, 2)), frac!(1, 2).checked_add_int(3));
+    assert_eq!(Some(frac!(-5, 2)), frac!(1, 2).checked_sub_int(3));
+
+    let f = Fraction16::new(i16::MAX - 1, 1);
+    assert_eq!(None, f.checked_add_int(5));
+    assert_eq!(Some(Fraction16::new(i16::MAX, 1)), f.checked_add_int(1));
+    assert_eq!(None, Fraction16::new(i16::MIN + 1, 1).checked_sub_int(2));
+    assert_eq!(None, Fraction16::new(1, 1000).checked_sub_int(100));
+}
+
+#[test]
+fn fraction_is_displayed_with_custom_separator() {
+    assert_eq!("3/4", frac!(3, 4).to_string_with_separator('/'));
+    assert_eq!("3:4", frac!(3, 4).to_string_with_separator(':'));
+    assert_eq!("-16:9", frac!(-16, 9).to_string_with_separator(':'));
+}
+
+#[test]
+fn fraction_is_parsed_with_custom_separator() {
+    assert_eq!(
+        Ok(frac!(16, 9)),
+        Fraction::parse_with_separator("16:9", ':')
+    );
+    assert_eq!(
+        Ok(frac!(-1, 2)),
+        Fraction::parse_with_separator("4:-8", ':')
+    );
+    assert_eq!(
+        Err(FractionParseError::IncorrectForm),
+        Fraction::<i32>::parse_with_separator("16/9", ':')
+    );
+}
+
+#[test]
+fn long_series_is_summed_without_spurious_overflow() {
+    // every partial sum k/(k + 1) fits, even though the product of all denominators would not
+    let n = 2000;
+    let sum: Fraction = (1..=n).map(|k| frac!(1, k * (k + 1))).sum();
+    assert_eq!(frac!(n, n + 1), sum);
+
+    let fracs = [frac!(1, 2), frac!(1, 3)];
+    assert_eq!(frac!(5, 6), fracs.iter().sum());
+
+    // scaling -127 to -254/2 would overflow, but the sum -127/2 fits
+    let fracs = [Fraction8::new(-127, 1), Fraction8::new(127, 2)];
+    assert_eq!(Fraction8::new(-127, 2), fracs.iter().sum());
+
+    let fracs = [
+        Fraction8::new(1, 3),
+        Fraction8::new(2, 3),
+        Fraction8::new(-128, 1),
+    ];
+    assert_eq!(Fraction8::new(-127, 1), fracs.iter().sum());
+}
+
+#[test]
+#[should_panic]
+fn sum_should_panic_on_overflow() {
+    let fracs = [Fraction8::new(127, 1), Fraction8::new(1, 1)];
+    let _: Fraction8 = fracs.iter().sum();
+}
+
+#[test]
+fn fractions_are_multiplied_together() {
+    let fracs = [
+        Fraction8::new(100, 3),
+        Fraction8::new(3, 100),
+        Fraction8::new(-1, 2),
+    ];
+    assert_eq!(Fraction8::new(-1, 2), fracs.iter().product());
+
+    let product: Fraction = (1..=100).map(|k| frac!(k, k + 1)).product();
+    assert_eq!(frac!(1, 101), product);
+}
+
+#[test]
+#[should_panic]
+fn product_should_panic_on_overflow() {
+    let fracs = [Fraction8::new(64, 1), Fraction8::new(2, 1)];
+    let _: Fraction8 = fracs.iter().product();
+}
+
+#[test]
+fn fraction_parts_are_converted_to_floats() {
+    assert_eq!((3.0, 4.0), frac!(3, 4).as_f64_pair());
+    assert_eq!((-1.0, 2.0), Fraction64::new(2, -4).as_f64_pair());
+}
+
+#[test]
+fn fractions_with_tied_float_keys_are_sorted_exactly() {
+    let two_53 = 9_007_199_254_740_992;
+
+    // all of these round to the same f64 key
+    let a = Fraction64::new(1, 1);
+    let b = Fraction64::new(two_53 + 2, two_53 + 1);
+    let c = Fraction64::new(two_53 + 1, two_53);
+
+    let mut v = vec![c, Fraction64::new(-1, 2), b, a];
+    Fraction64::sort_fractions(&mut v);
+
+    let expected_result = vec![Fraction64::new(-1, 2), a, b, c];
+    assert_eq!(expected_result, v);
+}
+
+#[test]
+fn long_decimal_is_reduced_while_parsed() {
+    // 10^14 does not fit in an i16, but the reduced value 1/16384 does
+    assert_eq!(
+        Ok(Fraction16::new(1, 16_384)),
+        Fraction16::parse_flexible("0.00006103515625")
+    );
+    assert_eq!(
+        Ok(Fraction16::new(-1, 2)),
+        Fraction16::parse_flexible("-0.50000000000")
+    );
+    assert_eq!(
+        Ok(Fraction8::new(-128, 1)),
+        Fraction8::parse_flexible("-128")
+    );
+    assert_eq!(Ok(frac!(-1, 2)), Fraction::parse_flexible("-.5"));
+}
+
+#[test]
+fn long_decimal_parse_err_when_overflowing() {
+    let expected_result = Err(FractionParseError::Overflow);
+
+    assert_eq!(expected_result, Fraction16::parse_flexible("0.123456789"));
+    assert_eq!(expected_result, Fraction16::parse_flexible("32767.5"));
+    assert!(Fraction16::parse_flexible("0.00001%")
+        .unwrap_err()
+        .is_overflow());
+}
+
+#[test]
+fn fraction_is_reciprocated_with_fallback() {
+    assert_eq!(frac!(1), frac!(0).reciprocal_or(frac!(1)));
+    assert_eq!(frac!(-4, 3), frac!(-3, 4).reciprocal_or(frac!(1)));
+}
+
+#[test]
+fn fraction_sign_is_split_from_magnitude() {
+    assert_eq!((Sign::Negative, frac!(3, 4)), frac!(-3, 4).split_sign());
+    assert_eq!((Sign::Positive, frac!(3, 4)), frac!(3, 4).split_sign());
+    assert_eq!((Sign::Positive, frac!(0)), frac!(0).split_sign());
+}
+
+#[test]
+fn pi_and_e_are_approximated() {
+    assert_eq!(frac!(3), Fraction::approximate_pi(1));
+    assert_eq!(frac!(22, 7), Fraction::approximate_pi(10));
+    assert_eq!(frac!(311, 99), Fraction::approximate_pi(100));
+    assert_eq!(frac!(355, 113), Fraction::approximate_pi(120));
+    assert_eq!(frac!(104_348, 33_215), Fraction::approximate_pi(33_215));
+
+    assert_eq!(frac!(3), Fraction::approximate_e(1));
+    assert_eq!(frac!(19, 7), Fraction::approximate_e(10));
+    assert_eq!(frac!(106, 39), Fraction::approximate_e(57));
+    assert_eq!(frac!(193, 71), Fraction::approximate_e(100));
+    assert_eq!(frac!(1457, 536), Fraction::approximate_e(1000));
+}
+
+#[test]
+fn fraction_total_cmp_agrees_with_sorting() {
+    let mut sorted = vec![
+        frac!(3, 4),
+        frac!(-1, 2),
+        frac!(0),
+        frac!(-7, 3),
+        frac!(1, 3),
+    ];
+    let mut total_sorted = sorted.clone();
+
+    sorted.sort();
+    total_sorted.sort_by(Fraction::total_cmp);
+
+    let expected_result = vec![
+        frac!(-7, 3),
+        frac!(-1, 2),
+        frac!(0),
+        frac!(1, 3),
+        frac!(3, 4),
+    ];
+    assert_eq!(expected_result, sorted);
+    assert_eq!(expected_result, total_sorted);
+}
+
+#[test]
+fn float_conversion_reports_errors() {
+    assert_eq!(
+        Ok(Fraction64::new(1, 3)),
+        Fraction64::try_from_f64(0.333_333, 10)
+    );
+    assert_eq!(
+        Ok(Fraction64::new(-5, 4)),
+        Fraction64::try_from_f64(-1.25, 100)
+    );
+
+    assert_eq!(
+        Err(FloatConversionError::NotFinite),
+        Fraction64::try_from_f64(f64::NAN, 10)
+    );
+    assert_eq!(
+        Err(FloatConversionError::NotFinite),
+        Fraction64::try_from_f64(f64::NEG_INFINITY, 10)
+    );
+    assert_eq!(
+        Err(FloatConversionError::OutOfRange),
+        Fraction64::try_from_f64(1e30, 10)
+    );
+}
+
+#[test]
+fn simplest_fraction_in_interval_is_found() {
+    assert_eq!(
+        frac!(2, 5),
+        Fraction::simplest_between(&frac!(1, 3), &frac!(1, 2))
+    );
+    assert_eq!(
+        frac!(12, 5),
+        Fraction::simplest_between(&frac!(7, 3), &frac!(5, 2))
+    );
+    assert_eq!(
+        frac!(1, 101),
+        Fraction::simplest_between(&frac!(0), &frac!(1, 100))
+    );
+    assert_eq!(
+        frac!(-2, 5),
+        Fraction::simplest_between(&frac!(-1, 2), &frac!(-1, 3))
+    );
+
+    // intervals spanning integers
+    assert_eq!(
+        frac!(1),
+        Fraction::simplest_between(&frac!(2, 3), &frac!(5, 4))
+    );
+    assert_eq!(
+        frac!(2),
+        Fraction::simplest_between(&frac!(3, 2), &frac!(7, 2))
+    );
+    assert_eq!(
+        frac!(0),
+        Fraction::simplest_between(&frac!(-1, 2), &frac!(1, 3))
+    );
+    assert_eq!(
+        frac!(-1),
+        Fraction::simplest_between(&frac!(-3, 2), &frac!(0))
+    );
+}
+
+#[test]
+fn fractions_are_summed_over_common_denominator() {
+    assert_eq!(
+        Some((5, 12)),
+        Fraction::checked_sum_lcm(&[frac!(1, 4), frac!(1, 6)])
+    );
+    assert_eq!(
+        Some((6, 12)),
+        Fraction::checked_sum_lcm(&[frac!(1, 4), frac!(1, 6), frac!(1, 12)])
+    );
+    assert_eq!(Some((0, 1)), Fraction::<i32>::checked_sum_lcm(&[]));
+
+    let coprime = [Fraction8::new(1, 11), Fraction8::new(1, 13)];
+    assert_eq!(None, Fraction8::checked_sum_lcm(&coprime));
+    assert_eq!(
+        None,
+        Fraction8::checked_sum_lcm(&[Fraction8::new(100, 1), Fraction8::new(100, 1)])
+    );
+}
+
+#[test]
+fn delimited_fractions_are_parsed() {
+    let expected_result = Ok(vec![frac!(1, 2), frac!(3, 4), frac!(5, 6)]);
+    assert_eq!(
+        expected_result,
+        Fraction::parse_delimited("1/2;3/4;5/6", ';')
+    );
+    assert_eq!(
+        expected_result,
+        Fraction::parse_delimited("2/4, 3/4, 5/6", ',')
+    );
+
+    let result = Fraction::<i32>::parse_delimited("1/2;3:4;5/6", ';');
+    assert_eq!(Err(FractionParseError::IncorrectForm), result);
+}
+
+#[test]
+fn fraction_backing_is_mapped() {
+    let f = frac!(-3, 4);
+    let expected_result = Fraction64::new(-3, 4);
+
+    assert_eq!(expected_result, f.map(|x| x as i64));
+}
+
+#[test]
+fn fraction_negation_is_checked() {
+    assert_eq!(None, Fraction8::new(i8::MIN, 3).checked_neg());
+    assert_eq!(
+        Some(Fraction8::new(i8::MAX, 3)),
+        Fraction8::new(-i8::MAX, 3).checked_neg()
+    );
+    assert_eq!(Some(frac!(-1, 2)), frac!(1, 2).checked_neg());
+    assert_eq!(Some(frac!(0)), frac!(0).checked_neg());
+}
+
+#[test]
+fn fractions_are_aligned_to_common_denominator() {
+    assert_eq!(
+        vec![(3, 6), (2, 6)],
+        Fraction::align_to_lcm(&[frac!(1, 2), frac!(1, 3)])
+    );
+    assert_eq!(
+        vec![(-3, 12), (10, 12), (12, 12)],
+        Fraction::align_to_lcm(&[frac!(-1, 4), frac!(5, 6), frac!(1)])
+    );
+}
+
+#[test]
+fn fractions_are_created_from_checked_pairs() {
+    let pairs = vec![(2, 4), (3, -9), (0, 5)];
+    let expected_result = Ok(vec![frac!(1, 2), frac!(-1, 3), frac!(0)]);
+    assert_eq!(expected_result, Fraction::from_pairs_checked(pairs));
+
+    let pairs = vec![(1, 2), (1, 0), (3, 4)];
+    let expected_result = Err((1, ConstructionError::ZeroDenominator));
+    assert_eq!(expected_result, Fraction::from_pairs_checked(pairs));
+
+    let pairs = vec![(1i8, 2i8), (5, 7), (i8::MIN, -1)];
+    let expected_result = Err((2, ConstructionError::Overflow));
+    assert_eq!(expected_result, Fraction8::from_pairs_checked(pairs));
+}
+
+#[test]
+fn fraction_dyadic_exponent_is_found() {
+    assert_eq!(Some(3), frac!(3, 8).dyadic_exponent());
+    assert_eq!(Some(0), frac!(1, 1).dyadic_exponent());
+    assert_eq!(
+        Some(6),
+        Fraction8::new(-1, 2).pow_reduced(6).dyadic_exponent()
+    );
+    assert_eq!(None, frac!(1, 3).dyadic_exponent());
+    assert_eq!(None, frac!(1, 12).dyadic_exponent());
+}
+
+#[test]
+fn fraction_is_divided_exactly_by_integer() {
+    assert_eq!(3, frac!(6, 5).gcd_with_int(9));
+    assert_eq!(1, frac!(6, 5).gcd_with_int(5));
+
+    assert_eq!(Some(frac!(2, 5)), frac!(6, 5).divide_exact(3));
+    assert_eq!(Some(frac!(-2, 5)), frac!(6, 5).divide_exact(-3));
+    assert_eq!(None, frac!(6, 5).divide_exact(4));
+    assert_eq!(None, frac!(6, 5).divide_exact(0));
+    assert_eq!(None, Fraction8::new(-128, 1).divide_exact(-1));
+}
+
+#[test]
+fn fraction_powers_are_yielded_lazily() {
+    let expected_result = vec![frac!(1), frac!(1, 2), frac!(1, 4), frac!(1, 8)];
+    assert_eq!(
+        expected_result,
+        frac!(1, 2).powers().take(4).collect::<Vec<_>>()
+    );
+
+    // 3^5 does not fit in an i8
+    let powers: Vec<Fraction8> = Fraction8::new(3, 1).powers().collect();
+    assert_eq!(5, powers.len());
+    assert_eq!(Fraction8::new(81, 1), powers[4]);
+}
+
+#[test]
+fn polynomial_is_evaluated_exactly() {
+    let coeffs = [frac!(1), frac!(2), frac!(3)];
+    assert_eq!(
+        frac!(11, 4),
+        Fraction::eval_polynomial(&coeffs, frac!(1, 2))
+    );
+    assert_eq!(frac!(2), Fraction::eval_polynomial(&coeffs, frac!(-1)));
+    assert_eq!(frac!(0), Fraction::eval_polynomial(&[], frac!(1, 2)));
+}
+
+#[test]
+#[should_panic]
+fn polynomial_evaluation_should_panic_on_overflow() {
+    let coeffs = [Fraction8::new(1, 1), Fraction8::new(100, 1)];
+    Fraction8::eval_polynomial(&coeffs, Fraction8::new(2, 1));
+}
+
+#[test]
+fn fraction_is_rounded_half_to_even() {
+    assert_eq!(0, frac!(1, 2).round_half_even());
+    assert_eq!(2, frac!(3, 2).round_half_even());
+    assert_eq!(2, frac!(5, 2).round_half_even());
+    assert_eq!(-2, frac!(-5, 2).round_half_even());
+    assert_eq!(-2, frac!(-3, 2).round_half_even());
+
+    assert_eq!(1, frac!(2, 3).round_half_even());
+    assert_eq!(-1, frac!(-4, 3).round_half_even());
+    assert_eq!(7, frac!(7).round_half_even());
+}
+
+#[test]
+fn fraction_is_rendered_as_mixed_number_with_separator() {
+    assert_eq!("2 1/3", frac!(7, 3).to_mixed_string(" "));
+    assert_eq!("2-1/3", frac!(7, 3).to_mixed_string("-"));
+    assert_eq!("-2 and 1/3", frac!(-7, 3).to_mixed_string(" and "));
+    assert_eq!("4", frac!(8, 2).to_mixed_string("-"));
+}
+
+#[test]
+fn fraction_denominator_is_clamped_in_place() {
+    let mut f = frac!(355, 113);
+
+    f.clamp_denominator(1000);
+    assert_eq!(frac!(355, 113), f);
+
+    f.clamp_denominator(10);
+    assert_eq!(frac!(22, 7), f);
+}
+
+#[test]
+fn fraction_sign_char_is_returned() {
+    assert_eq!(Some('-'), frac!(-1, 2).sign_char());
+    assert_eq!(None, frac!(0).sign_char());
+    assert_eq!(None, frac!(1, 2).sign_char());
+}
+
+#[test]
+fn unreduced_pairs_are_compared() {
+    assert!(util::equivalent((2, 4), (1, 2)));
+    assert!(util::equivalent((1, -3), (-2, 6)));
+    assert!(util::equivalent((0, 5), (0, -1)));
+    assert!(!util::equivalent((1, 2), (1, 3)));
+    assert!(!util::equivalent((1, 2), (1, -2)));
+}
+
+#[test]
+fn largest_unit_fraction_below_is_found() {
+    assert_eq!(frac!(1, 2), frac!(2, 3).largest_unit_fraction_below());
+    assert_eq!(frac!(1, 4), frac!(4, 13).largest_unit_fraction_below());
+    assert_eq!(frac!(1, 5), frac!(1, 5).largest_unit_fraction_below());
+    assert_eq!(frac!(1), frac!(7, 2).largest_unit_fraction_below());
+}
+
+#[test]
+#[should_panic]
+fn largest_unit_fraction_below_should_panic_for_non_positive() {
+    frac!(-1, 2).largest_unit_fraction_below();
+}
+
+#[test]
+fn fraction_cross_products_are_computed() {
+    assert_eq!((8, 9), frac!(2, 3).cross_multiply(&frac!(3, 4)));
+    assert_eq!((-5, 6), frac!(-1, 2).cross_multiply(&frac!(3, 5)));
+    assert_eq!((2, 2), frac!(1, 2).cross_multiply(&frac!(2, 4)));
+}
+
+#[test]
+fn pair_sign_is_normalized_without_reduction() {
+    assert_eq!((-1, 5), util::with_normalized_sign(1, -5));
+    assert_eq!((2, 4), util::with_normalized_sign(2, 4));
+    assert_eq!((2, 4), util::with_normalized_sign(-2, -4));
+}
+
+#[test]
+#[should_panic]
+fn pair_sign_normalization_should_panic_with_zero_denominator() {
+    util::with_normalized_sign(1, 0);
+}
+
+#[test]
+fn scaled_pair_should_not_be_reduced() {
+    assert_eq!((3, 6), frac!(1, 2).scale_pair(3));
+    assert_eq!((-4, 10), frac!(-2, 5).scale_pair(2));
+}
+
+#[test]
+fn scaled_fraction_is_reduced_back() {
+    let f = frac!(-2, 5);
+
+    assert_eq!(f, f.scale_num_den(7));
+}
+
+#[test]
+#[should_panic]
+fn scale_pair_should_panic_with_non_positive_factor() {
+    frac!(1, 2).scale_pair(-1);
+}
+
+#[test]
+fn fraction_of_amount_divides_exactly() {
+    let expected_result = frac!(25, 1);
+
+    assert_eq!(expected_result, frac!(1, 4).of(100));
+    assert_eq!(frac!(-60, 1), frac!(-3, 5).of(100));
+}
+
+#[test]
+fn fraction_of_amount_is_exact_when_not_divisible() {
+    assert_eq!(frac!(100, 3), frac!(1, 3).of(100));
+    assert_eq!(frac!(14, 3), frac!(2, 3).of(7));
+}